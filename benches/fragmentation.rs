@@ -0,0 +1,66 @@
+//! Resolution cost of `Single` as the number of archetypes grows
+//!
+//! The world holds `2^k` archetypes built from combinations of `k` marker components, with one
+//! `Data` entity per archetype. Only a single entity also has `Player`, so both `Single` and
+//! `Query::single` have to skip every other archetype. Run with `cargo bench --bench fragmentation`.
+
+use bevy_ecs::{prelude::*, system::SystemState};
+use bevy_single::Single;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Data(f32);
+
+macro_rules! fragmented_world {
+    ($($bit:literal => $marker:ident),*) => {
+        $(
+            #[derive(Component)]
+            struct $marker;
+        )*
+
+        fn fragmented_world(archetypes: u32) -> World {
+            let mut world = World::new();
+
+            for bits in 0..archetypes {
+                let mut entity = world.spawn(Data(0.0));
+
+                $(
+                    if bits & (1 << $bit) != 0 {
+                        entity.insert($marker);
+                    }
+                )*
+            }
+
+            world.spawn((Data(1.0), Player));
+            world
+        }
+    };
+}
+
+fragmented_world!(0 => M0, 1 => M1, 2 => M2, 3 => M3, 4 => M4, 5 => M5, 6 => M6, 7 => M7, 8 => M8, 9 => M9);
+
+fn fragmentation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fragmentation");
+
+    for archetypes in [16, 128, 1024] {
+        let mut world = fragmented_world(archetypes);
+
+        let mut single: SystemState<Single<&Data, With<Player>>> = SystemState::new(&mut world);
+        group.bench_with_input(BenchmarkId::new("Single", archetypes), &archetypes, |b, _| {
+            b.iter(|| black_box(single.get(&world).0 .0))
+        });
+
+        let mut query: SystemState<Query<&Data, With<Player>>> = SystemState::new(&mut world);
+        group.bench_with_input(BenchmarkId::new("Query::single", archetypes), &archetypes, |b, _| {
+            b.iter(|| black_box(query.get(&world).single().0))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, fragmentation);
+criterion_main!(benches);
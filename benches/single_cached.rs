@@ -0,0 +1,43 @@
+//! Cost of resolving a singleton from `World` with and without a cached query state
+//!
+//! `WorldSingleExt::single` builds a fresh `QueryState` on every call, while `single_cached` keeps one
+//! in a resource and only updates it for new archetypes. This compares both on the same world, with
+//! the `Player` among many other `Data` entities. Run with `cargo bench --bench single_cached`.
+
+use bevy_ecs::prelude::*;
+use bevy_single::WorldSingleExt;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Data(f32);
+
+fn world_with(entities: u32) -> World {
+    let mut world = World::new();
+    world.spawn_batch((0..entities).map(|_| Data(0.0)));
+    world.spawn((Data(1.0), Player));
+    world
+}
+
+fn single_cached(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_cached");
+
+    for entities in [16, 1024, 65536] {
+        let mut world = world_with(entities);
+
+        group.bench_with_input(BenchmarkId::new("World::single", entities), &entities, |b, _| {
+            b.iter(|| black_box(world.single::<&Data, With<Player>>().0))
+        });
+
+        group.bench_with_input(BenchmarkId::new("World::single_cached", entities), &entities, |b, _| {
+            b.iter(|| black_box(world.single_cached::<&Data, With<Player>>().0))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, single_cached);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+//! Resolution cost of `Single` for singletons marked with a sparse set component
+//!
+//! Queries filtered by a sparse set component iterate archetypes instead of tables, so this compares
+//! `Single` for a table and a sparse set `Player` marker among many other `Data` entities, next to
+//! `Query::single` for the same filter. Run with `cargo bench --bench sparse_set`.
+
+use bevy_ecs::{prelude::*, system::SystemState};
+use bevy_single::Single;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[derive(Component)]
+struct TablePlayer;
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+struct SparsePlayer;
+
+#[derive(Component)]
+struct Data(f32);
+
+fn world_with(entities: u32) -> World {
+    let mut world = World::new();
+    world.spawn_batch((0..entities).map(|_| Data(0.0)));
+    world.spawn((Data(1.0), TablePlayer));
+    world.spawn((Data(2.0), SparsePlayer));
+    world
+}
+
+fn sparse_set(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sparse_set");
+
+    for entities in [16, 1024, 65536] {
+        let mut world = world_with(entities);
+
+        let mut table: SystemState<Single<&Data, With<TablePlayer>>> = SystemState::new(&mut world);
+        group.bench_with_input(BenchmarkId::new("Single table", entities), &entities, |b, _| {
+            b.iter(|| black_box(table.get(&world).0 .0))
+        });
+
+        let mut sparse: SystemState<Single<&Data, With<SparsePlayer>>> = SystemState::new(&mut world);
+        group.bench_with_input(BenchmarkId::new("Single sparse set", entities), &entities, |b, _| {
+            b.iter(|| black_box(sparse.get(&world).0 .0))
+        });
+
+        let mut query: SystemState<Query<&Data, With<SparsePlayer>>> = SystemState::new(&mut world);
+        group.bench_with_input(BenchmarkId::new("Query::single sparse set", entities), &entities, |b, _| {
+            b.iter(|| black_box(query.get(&world).single().0))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, sparse_set);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Reads the `leafwing-input-manager` `ActionState` of the player through `Single`
+//!
+//! Run with `cargo run --example leafwing_jump --features leafwing-example`
+
+use bevy::prelude::*;
+use bevy_single::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+#[derive(Actionlike, PartialEq, Eq, Hash, Clone, Copy, Debug, Reflect)]
+enum Action {
+    Jump,
+}
+
+#[derive(Component)]
+struct Player;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, InputManagerPlugin::<Action>::default()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, jump)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::splat(40.0)),
+                ..default()
+            },
+            ..default()
+        },
+        InputManagerBundle::with_map(InputMap::new([(Action::Jump, KeyCode::Space)])),
+        Player,
+    ));
+}
+
+fn jump(
+    Single((action_state, mut transform)): Single<(&ActionState<Action>, &mut Transform), With<Player>>,
+) {
+    if action_state.just_pressed(&Action::Jump) {
+        transform.translation.y += 50.0;
+    }
+}
@@ -0,0 +1,73 @@
+//! Moves a `bevy_rapier2d` character controller with WASD, resolving the player through `Single`
+//!
+//! Run with `cargo run --example rapier_character_controller --features rapier-example`
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use bevy_single::prelude::*;
+
+#[derive(Component)]
+struct Player {
+    speed: f32,
+}
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_with_wasd, report_grounded))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -200.0, 0.0)),
+        Collider::cuboid(500.0, 20.0),
+    ));
+
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, 100.0, 0.0)),
+        RigidBody::KinematicPositionBased,
+        Collider::ball(20.0),
+        KinematicCharacterController::default(),
+        Player { speed: 300.0 },
+    ));
+}
+
+fn move_with_wasd(
+    Single((mut controller, player)): Single<(&mut KinematicCharacterController, &Player)>,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+) {
+    const WASD_DISPATCH: [(KeyCode, Vec2); 4] = [
+        (KeyCode::KeyW, Vec2::Y),
+        (KeyCode::KeyA, Vec2::NEG_X),
+        (KeyCode::KeyS, Vec2::NEG_Y),
+        (KeyCode::KeyD, Vec2::X),
+    ];
+
+    let mut translation = Vec2::NEG_Y;
+
+    for (key, vector) in WASD_DISPATCH {
+        if input.pressed(key) {
+            translation += vector;
+        }
+    }
+
+    controller.translation = Some(translation.normalize_or_zero() * player.speed * time.delta_seconds());
+}
+
+// The output is only inserted after the first physics step, so the lookup is optional here
+fn report_grounded(output: OptionalSingle<Ref<KinematicCharacterControllerOutput>, With<Player>>) {
+    if let Some(output) = output.0 {
+        if output.is_changed() && output.grounded {
+            info!("player is grounded");
+        }
+    }
+}
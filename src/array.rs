@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+use core::{any::type_name, ops::{Deref, Index}};
+
+use bevy_ecs::{archetype::Archetype, component::{Component, Tick}, entity::Entity, query::{QueryFilter, QueryState, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{init_query_state, query_iter, system_last_run, SingleDescriptor, LISTED_MATCHES, REPORTED_MATCHES};
+
+
+/// Get exactly `N` entities from query, reading their `T` components into a fixed array
+/// 
+/// Panics unless exactly `N` entities match, so `SingleArray<1, T>` behaves like `Single<&T>`, listing
+/// the matches with the same cap. The order of the array is the query iteration order.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Paddle(f32);
+/// 
+/// pub fn paddle_gap(paddles: SingleArray<2, Paddle>) -> f32 {
+///     (paddles[0].0 - paddles[1].0).abs()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn(Paddle(-5.0));
+/// # world.spawn(Paddle(5.0));
+/// # assert_eq!(world.run_system_once(paddle_gap), 10.0);
+/// ```
+/// 
+/// A third match panics:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Paddle(f32);
+/// pub fn paddle_gap(paddles: SingleArray<2, Paddle>) -> f32 {
+///     (paddles[0].0 - paddles[1].0).abs()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn(Paddle(-5.0));
+/// # world.spawn(Paddle(5.0));
+/// # world.spawn(Paddle(0.0));
+/// # world.run_system_once(paddle_gap);
+/// ```
+pub struct SingleArray<'world, 'state, const N: usize, T: Component, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, &'static T, F> = ()>(pub [<Desc::D as WorldQuery>::Item<'world>; N]);
+
+
+impl<'world, 'state, const N: usize, T: Component, F: QueryFilter> Deref for SingleArray<'world, 'state, N, T, F> {
+    type Target = [&'world T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Indexing yields the component itself rather than the reference stored in the array
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component, Clone, PartialEq, Debug)]
+/// struct Waypoint(u32);
+/// 
+/// pub fn route(waypoints: SingleArray<3, Waypoint>) -> Vec<Waypoint> {
+///     (0..3).map(|index| waypoints[index].clone()).collect()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn_batch([Waypoint(1), Waypoint(2), Waypoint(3)]);
+/// # let mut route = world.run_system_once(route);
+/// # route.sort_by_key(|waypoint| waypoint.0);
+/// # assert_eq!(route, [Waypoint(1), Waypoint(2), Waypoint(3)]);
+/// ```
+impl<'world, 'state, const N: usize, T: Component, F: QueryFilter> Index<usize> for SingleArray<'world, 'state, N, T, F> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.0[index]
+    }
+}
+
+unsafe impl<'w, 's, const N: usize, T: Component, F: QueryFilter + 'static> ReadOnlySystemParam
+    for SingleArray<'w, 's, N, T, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, const N: usize, T: Component, F: QueryFilter + 'static> SystemParam for SingleArray<'ww, 'ss, N, T, F> {
+    type State = QueryState<&'static T, F>;
+    type Item<'w, 's> = SingleArray<'w, 's, N, T, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, &'static T, F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs. The items are shared references,
+        // so handing out several of them can not alias mutably.
+        unsafe {
+            state.validate_world(world.id());
+            let last_run = system_last_run(system_meta, world, change_tick);
+
+            let mut items = query_iter(state, world, last_run, change_tick);
+            let array: [Option<&'w T>; N] = core::array::from_fn(|_| items.next());
+
+            if array.iter().any(Option::is_none) || items.next().is_some() {
+                // Same capped count and listing as `Single`
+                let mut matches = <Query<&T, F> as SystemParam>::get_param(state, system_meta, world, change_tick);
+                let entities: Vec<Entity> = matches.transmute_lens_filtered::<Entity, F>().query().iter().take(REPORTED_MATCHES + 1).collect();
+                let listed = &entities[..entities.len().min(LISTED_MATCHES)];
+                let more = if entities.len() > LISTED_MATCHES { " and more" } else { "" };
+                let query = type_name::<QueryState<&T, F>>();
+
+                if entities.len() > REPORTED_MATCHES {
+                    panic!("Expected exactly {N} entities, but more than {REPORTED_MATCHES} fit the query {query}: {listed:?}{more}")
+                }
+
+                panic!("Expected exactly {N} entities, but {} fit the query {query}: {listed:?}{more}", entities.len())
+            }
+
+            SingleArray(array.map(Option::unwrap))
+        }
+    }
+}
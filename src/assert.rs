@@ -0,0 +1,87 @@
+use alloc::{format, string::String, vec::Vec};
+use core::any::type_name;
+
+use bevy_app::{App, Plugin, PostStartup};
+use bevy_ecs::{query::QueryFilter, world::World};
+
+use crate::count_matches;
+
+
+/// Checks in [`PostStartup`] that each expected filter matches exactly one entity
+/// 
+/// All failing filters are reported together in one panic, so a broken scene setup shows every missing or
+/// duplicated singleton at once instead of the first system that happens to resolve one.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::SingleAssertPlugin;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(Player);
+///     commands.spawn(MainCamera);
+/// }
+/// 
+/// let mut app = App::new();
+/// app.add_systems(Startup, setup)
+///     .add_plugins(SingleAssertPlugin::default().expect::<With<Player>>().expect::<With<MainCamera>>());
+/// app.update();
+/// ```
+/// 
+/// A missing singleton panics after startup:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy_single::SingleAssertPlugin;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(Player);
+/// }
+/// 
+/// let mut app = App::new();
+/// app.add_systems(Startup, setup)
+///     .add_plugins(SingleAssertPlugin::default().expect::<With<Player>>().expect::<With<MainCamera>>());
+/// app.update();
+/// ```
+#[derive(Default)]
+pub struct SingleAssertPlugin {
+    checks: Vec<fn(&mut World) -> Option<String>>,
+}
+
+impl SingleAssertPlugin {
+    /// Expects the filter `F` to match exactly one entity after startup
+    pub fn expect<F: QueryFilter + 'static>(mut self) -> Self {
+        self.checks.push(check_single::<F>);
+        self
+    }
+}
+
+impl Plugin for SingleAssertPlugin {
+    fn build(&self, app: &mut App) {
+        let checks = self.checks.clone();
+
+        app.add_systems(PostStartup, move |world: &mut World| {
+            let failures: Vec<String> = checks.iter().filter_map(|check| check(world)).collect();
+
+            if !failures.is_empty() {
+                panic!("Expected singletons did not resolve after startup:\n{}", failures.join("\n"));
+            }
+        });
+    }
+}
+
+
+/// Returns a description of the failure if `F` does not match exactly one entity
+fn check_single<F: QueryFilter + 'static>(world: &mut World) -> Option<String> {
+    match count_matches::<F>(world) {
+        1 => None,
+        0 => Some(format!("- No entities fit the filter {}", type_name::<F>())),
+        count => Some(format!("- Multiple entities ({count}) fit the filter {}", type_name::<F>())),
+    }
+}
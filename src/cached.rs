@@ -0,0 +1,114 @@
+use core::ops::{Deref, DerefMut};
+
+use bevy_ecs::{archetype::Archetype, component::Tick, entity::Entity, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{fetch_entity, init_query_state, resolve_single, Duplicates, SingleDescriptor};
+
+
+/// Get single entity from query, remembering the resolved [`Entity`] across system runs
+/// 
+/// Items borrow the world only for one system run, so they can not be kept in a `Local`. This param
+/// keeps the [`Entity`] instead and fetches the item of that entity directly on later runs. The query
+/// is only resolved again, with the same panics as [`Single`](crate::Single), once the cached entity
+/// no longer matches. Entities matching in the meantime are not noticed until then.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::System;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Level(u32);
+/// 
+/// pub fn current_level(level: CachedSingleEntity<&Level>) -> u32 {
+///     level.0
+/// }
+/// # let mut world = World::new();
+/// # let first = world.spawn(Level(1)).id();
+/// # let mut system = IntoSystem::into_system(current_level);
+/// # system.initialize(&mut world);
+/// # assert_eq!(system.run((), &mut world), 1);
+/// # assert_eq!(system.run((), &mut world), 1);
+/// # // The cached entity still matches, so the new level is not noticed yet
+/// # world.spawn(Level(2));
+/// # assert_eq!(system.run((), &mut world), 1);
+/// # world.despawn(first);
+/// # assert_eq!(system.run((), &mut world), 2);
+/// ```
+pub struct CachedSingleEntity<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub Entity, pub <Desc::D as WorldQuery>::Item<'world>);
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for CachedSingleEntity<'world, 'state, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for CachedSingleEntity<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.1
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for CachedSingleEntity<'w, 's, D, F>
+{
+}
+
+
+/// State of [`CachedSingleEntity`]
+#[doc(hidden)]
+pub struct CachedEntityState<D: QueryData, F: QueryFilter> {
+    query: QueryState<(Entity, D), F>,
+    entity: Option<Entity>,
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for CachedSingleEntity<'ww, 'ss, D, F> {
+    type State = CachedEntityState<D, F>;
+    type Item<'w, 's> = CachedSingleEntity<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        CachedEntityState {
+            query: init_query_state(world, system_meta),
+            entity: None,
+        }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, (Entity, D), F> as SystemParam>::new_archetype(&mut state.query, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs. Only one item is fetched.
+        unsafe {
+            if let Some(cached) = state.entity {
+                if let Ok((entity, single)) = fetch_entity(&mut state.query, system_meta, world, change_tick, cached) {
+                    return CachedSingleEntity(entity, single);
+                }
+            }
+
+            let (entity, single) = resolve_single(&mut state.query, system_meta, world, change_tick, Duplicates::Panic);
+            state.entity = Some(entity);
+
+            CachedSingleEntity(entity, single)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Run conditions based on singles
+
+use bevy_ecs::{component::Component, query::QueryFilter};
+
+use crate::TrySingle;
+
+
+/// Run condition that is `true` if exactly one entity matches the filter `F`
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::{conditions::single_exists, prelude::*};
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Resource, Default)]
+/// struct Ticks(u32);
+/// 
+/// fn tick(mut ticks: ResMut<Ticks>) {
+///     ticks.0 += 1;
+/// }
+/// 
+/// let mut world = World::new();
+/// world.init_resource::<Ticks>();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(tick.run_if(single_exists::<With<Player>>));
+/// 
+/// schedule.run(&mut world);
+/// world.spawn(Player);
+/// schedule.run(&mut world);
+/// world.spawn(Player);
+/// schedule.run(&mut world);
+/// assert_eq!(world.resource::<Ticks>().0, 1);
+/// ```
+pub fn single_exists<F: QueryFilter + 'static>(single: TrySingle<(), F>) -> bool {
+    single.is_ok()
+}
+
+/// Run condition that is `true` if the single `T` matching the filter `F` satisfies `predicate`
+/// 
+/// `false` if there is not exactly one match.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::{conditions::single_matches, prelude::*};
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// #[derive(Resource, Default)]
+/// struct Warnings(u32);
+/// 
+/// fn warn_low_health(mut warnings: ResMut<Warnings>) {
+///     warnings.0 += 1;
+/// }
+/// 
+/// let mut world = World::new();
+/// world.init_resource::<Warnings>();
+/// let player = world.spawn((Health(50), Player)).id();
+/// 
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(warn_low_health.run_if(single_matches::<Health, With<Player>>(|health| health.0 < 20)));
+/// 
+/// schedule.run(&mut world);
+/// world.get_mut::<Health>(player).unwrap().0 = 10;
+/// schedule.run(&mut world);
+/// world.get_mut::<Health>(player).unwrap().0 = 30;
+/// schedule.run(&mut world);
+/// world.despawn(player);
+/// schedule.run(&mut world);
+/// assert_eq!(world.resource::<Warnings>().0, 1);
+/// ```
+pub fn single_matches<T: Component, F: QueryFilter + 'static>(
+    predicate: impl Fn(&T) -> bool + Clone + Send + Sync + 'static,
+) -> impl FnMut(TrySingle<&T, F>) -> bool + Clone {
+    move |single: TrySingle<&T, F>| single.map_or(false, &predicate)
+}
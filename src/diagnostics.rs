@@ -0,0 +1,53 @@
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{query::QueryFilter, system::Query};
+
+
+/// Records how many entities match the filter `F` every frame, to catch singletons that slowly multiply
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+/// # use bevy_single::SingleCountDiagnosticPlugin;
+/// # #[derive(Component)]
+/// # struct Player;
+/// const PLAYER_COUNT: DiagnosticPath = DiagnosticPath::const_new("single/player_count");
+/// 
+/// let mut app = App::new();
+/// app.add_plugins(SingleCountDiagnosticPlugin::<With<Player>>::new(PLAYER_COUNT));
+/// # app.world_mut().spawn(Player);
+/// # app.world_mut().spawn(Player);
+/// # app.world_mut().spawn(Player);
+/// # app.update();
+/// # let store = app.world().resource::<DiagnosticsStore>();
+/// # assert_eq!(store.get(&PLAYER_COUNT).unwrap().value(), Some(3.0));
+/// ```
+pub struct SingleCountDiagnosticPlugin<F: QueryFilter> {
+    path: DiagnosticPath,
+    _filter: PhantomData<fn() -> F>,
+}
+
+impl<F: QueryFilter> SingleCountDiagnosticPlugin<F> {
+    /// Creates the plugin, recording the count under `path`
+    pub fn new(path: DiagnosticPath) -> Self {
+        Self {
+            path,
+            _filter: PhantomData,
+        }
+    }
+}
+
+impl<F: QueryFilter + 'static> Plugin for SingleCountDiagnosticPlugin<F> {
+    fn build(&self, app: &mut App) {
+        let path = self.path.clone();
+
+        app.register_diagnostic(Diagnostic::new(path.clone()))
+            .add_systems(Update, move |matches: Query<(), F>, mut diagnostics: Diagnostics| {
+                diagnostics.add_measurement(&path, || matches.iter().count() as f64);
+            });
+    }
+}
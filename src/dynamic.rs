@@ -0,0 +1,147 @@
+use core::marker::PhantomData;
+
+use bevy_ecs::{archetype::Archetype, change_detection::MutUntyped, component::{ComponentId, Tick}, entity::Entity, ptr::Ptr, query::QueryState, system::{BuildableSystemParam, Query, Resource, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, FilteredEntityMut, World}};
+
+use crate::{resolve_single, Duplicates};
+
+
+/// Resource naming the component a [`DynamicSingle`] resolves
+pub trait DynamicComponent: Resource {
+    /// Component of the single, possibly registered at runtime
+    fn component_id(&self) -> ComponentId;
+}
+
+
+/// Single entity with a component only known by its [`ComponentId`], for scripting and editor tools
+/// 
+/// The id is read from the [`DynamicComponent`] resource `K` once, when the system is initialized, and
+/// write access to that component is registered like for a `Single<&mut T>`. Panics like
+/// [`Single`](crate::Single) if not exactly one entity has the component, or if `K` does not exist
+/// when the system is initialized.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::component::{ComponentDescriptor, ComponentId, StorageType};
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy::ptr::OwningPtr;
+/// # use bevy_single::{DynamicComponent, DynamicSingle};
+/// # use std::alloc::Layout;
+/// #[derive(Resource)]
+/// struct ScoreComponent(ComponentId);
+/// 
+/// impl DynamicComponent for ScoreComponent {
+///     fn component_id(&self) -> ComponentId {
+///         self.0
+///     }
+/// }
+/// 
+/// pub fn add_point(mut score: DynamicSingle<ScoreComponent>) {
+///     // SAFETY: The component was registered with the layout of `u64`
+///     unsafe { *score.get_mut().into_inner().deref_mut::<u64>() += 1 };
+/// }
+/// 
+/// let mut world = World::new();
+/// // SAFETY: `u64` needs no drop function
+/// let id = world.init_component_with_descriptor(unsafe {
+///     ComponentDescriptor::new_with_layout("Score", StorageType::Table, Layout::new::<u64>(), None)
+/// });
+/// world.insert_resource(ScoreComponent(id));
+/// 
+/// let entity = world.spawn_empty().id();
+/// // SAFETY: The pointer is a `u64`, matching the layout of the component
+/// OwningPtr::make(7u64, |ptr| unsafe {
+///     world.entity_mut(entity).insert_by_id(id, ptr);
+/// });
+/// 
+/// world.run_system_once(add_point);
+/// // SAFETY: The component was registered with the layout of `u64`
+/// assert_eq!(unsafe { *world.entity(entity).get_by_id(id).unwrap().deref::<u64>() }, 8);
+/// ```
+pub struct DynamicSingle<'world, 'state, K: DynamicComponent> {
+    entity: FilteredEntityMut<'world>,
+    component: ComponentId,
+    _marker: PhantomData<(&'state (), fn() -> K)>,
+}
+
+
+impl<'world, 'state, K: DynamicComponent> DynamicSingle<'world, 'state, K> {
+    /// Returns the resolved entity
+    pub fn entity(&self) -> Entity {
+        self.entity.id()
+    }
+
+    /// Returns the id of the component
+    pub fn component_id(&self) -> ComponentId {
+        self.component
+    }
+
+    /// Returns a pointer to the component
+    pub fn get(&self) -> Ptr<'_> {
+        // The query only matches entities with the component, and reads it
+        self.entity.get_by_id(self.component).unwrap()
+    }
+
+    /// Returns the component mutably, marking it changed once written
+    pub fn get_mut(&mut self) -> MutUntyped<'_> {
+        // The query only matches entities with the component, and writes it
+        self.entity.get_mut_by_id(self.component).unwrap()
+    }
+}
+
+
+/// State of [`DynamicSingle`]
+#[doc(hidden)]
+pub struct DynamicSingleState {
+    query: QueryState<FilteredEntityMut<'static>>,
+    component: ComponentId,
+}
+
+
+// SAFETY: The query is built with write access to the component and registered in SystemMeta by
+// `Query::build`. If it conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, K: DynamicComponent> SystemParam for DynamicSingle<'ww, 'ss, K> {
+    type State = DynamicSingleState;
+    type Item<'w, 's> = DynamicSingle<'w, 's, K>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let component = world
+            .get_resource::<K>()
+            .unwrap_or_else(|| panic!("Resource {} naming the component of a DynamicSingle does not exist", core::any::type_name::<K>()))
+            .component_id();
+
+        let query = <Query<FilteredEntityMut<'static>> as BuildableSystemParam>::build(world, system_meta, |builder| {
+            builder.with_id(component).mut_id(component);
+        });
+
+        DynamicSingleState { query, component }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, FilteredEntityMut<'static>> as SystemParam>::new_archetype(&mut state.query, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs.
+        let entity = unsafe { resolve_single(&mut state.query, system_meta, world, change_tick, Duplicates::Panic) };
+
+        DynamicSingle {
+            entity,
+            component: state.component,
+            _marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt::{self, Display};
+
+use bevy_ecs::query::QuerySingleError;
+
+
+/// Error resolving a single through one of the fallible APIs of this crate
+/// 
+/// Returned by [`TrySingle`](crate::TrySingle), [`WorldSingleExt`](crate::WorldSingleExt) and
+/// [`CachedSingleState`](crate::CachedSingleState), so downstream code has one error type to match on.
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::query::QuerySingleError;
+/// # use bevy_single::{prelude::*, CachedSingleState, SingleError};
+/// # #[derive(Component)]
+/// # struct Player;
+/// let mut world = World::new();
+/// assert!(matches!(world.get_single::<&Transform, With<Player>>(), Err(SingleError::Query(QuerySingleError::NoEntities(_)))));
+/// assert!(matches!(world.get_single_cached::<&Transform, With<Player>>(), Err(SingleError::Uninitialized)));
+/// 
+/// let state = CachedSingleState::<&Transform, With<Player>>::new(&mut world);
+/// let other = World::new();
+/// assert!(matches!(state.get(&other), Err(SingleError::WorldMismatch)));
+/// ```
+#[derive(Debug)]
+pub enum SingleError {
+    /// The query did not match exactly one entity
+    Query(QuerySingleError),
+    /// A cached state was used with a world other than the one it was created from
+    WorldMismatch,
+    /// A cached state was read before it was created
+    Uninitialized,
+}
+
+
+impl From<QuerySingleError> for SingleError {
+    fn from(error: QuerySingleError) -> Self {
+        Self::Query(error)
+    }
+}
+
+impl Display for SingleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Query(error) => write!(f, "{error}"),
+            Self::WorldMismatch => write!(f, "The cached single state was created from a different world"),
+            Self::Uninitialized => write!(f, "No cached single state exists for the query yet"),
+        }
+    }
+}
+
+impl std::error::Error for SingleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Query(error) => Some(error),
+            Self::WorldMismatch | Self::Uninitialized => None,
+        }
+    }
+}
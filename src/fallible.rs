@@ -0,0 +1,344 @@
+use core::ops::{Deref, DerefMut};
+
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, QuerySingleError, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{init_query_state, query_single, resolve_single, system_last_run, Duplicates, SingleDescriptor, SingleError};
+
+
+/// Single entity from query that does not panic, keeping the [`SingleError`] instead
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy::ecs::query::QuerySingleError;
+/// # use bevy_single::{prelude::*, SingleError};
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: TrySingle<&Transform, With<Player>>) -> Result<f32, SingleError> {
+///     Ok(player.try_into_inner()?.translation.y)
+/// }
+/// # let mut world = World::new();
+/// # assert!(matches!(world.run_system_once(player_height), Err(SingleError::Query(QuerySingleError::NoEntities(_)))));
+/// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+/// # assert_eq!(world.run_system_once(player_height).unwrap(), 3.0);
+/// # world.spawn((Transform::default(), Player));
+/// # assert!(matches!(world.run_system_once(player_height), Err(SingleError::Query(QuerySingleError::MultipleEntities(_)))));
+/// ```
+pub struct TrySingle<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub Result<<Desc::D as WorldQuery>::Item<'world>, SingleError>);
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> TrySingle<'world, 'state, D, F, Desc> {
+    /// Returns the inner result by value, to be used with `?`
+    pub fn try_into_inner(self) -> Result<<Desc::D as WorldQuery>::Item<'world>, SingleError> {
+        self.0
+    }
+
+    /// Maps the item with `f`, or returns `default` if the single could not be resolved
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// pub fn player_height(player: TrySingle<&Transform, With<Player>>) -> f32 {
+    ///     player.map_or(0.0, |transform| transform.translation.y)
+    /// }
+    /// # let mut world = World::new();
+    /// # assert_eq!(world.run_system_once(player_height), 0.0);
+    /// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+    /// # assert_eq!(world.run_system_once(player_height), 3.0);
+    /// ```
+    pub fn map_or<U>(self, default: U, f: impl FnOnce(<Desc::D as WorldQuery>::Item<'world>) -> U) -> U {
+        self.0.map_or(default, f)
+    }
+
+    /// Maps the item with `f`, or the error with `default` if the single could not be resolved
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// pub fn player_status(player: TrySingle<&Name, With<Player>>) -> String {
+    ///     player.map_or_else(|error| error.to_string(), |name| name.to_string())
+    /// }
+    /// # let mut world = World::new();
+    /// # assert!(world.run_system_once(player_status).starts_with("No entities fit the query"));
+    /// # world.spawn((Name::new("Player"), Player));
+    /// # assert_eq!(world.run_system_once(player_status), "Player");
+    /// ```
+    pub fn map_or_else<U>(
+        self,
+        default: impl FnOnce(SingleError) -> U,
+        f: impl FnOnce(<Desc::D as WorldQuery>::Item<'world>) -> U,
+    ) -> U {
+        self.0.map_or_else(default, f)
+    }
+
+    /// Runs `f` on the item if the single was resolved, and returns `self` for chaining
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Resource, Default)]
+    /// struct Resolved(u32);
+    /// 
+    /// pub fn player_height(player: TrySingle<&Transform, With<Player>>, mut resolved: ResMut<Resolved>) -> f32 {
+    ///     player
+    ///         .inspect(|_| resolved.0 += 1)
+    ///         .map_or(0.0, |transform| transform.translation.y)
+    /// }
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Resolved>();
+    /// # assert_eq!(world.run_system_once(player_height), 0.0);
+    /// # assert_eq!(world.resource::<Resolved>().0, 0);
+    /// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+    /// # assert_eq!(world.run_system_once(player_height), 3.0);
+    /// # assert_eq!(world.resource::<Resolved>().0, 1);
+    /// ```
+    pub fn inspect(self, f: impl FnOnce(&<Desc::D as WorldQuery>::Item<'world>)) -> Self {
+        if let Ok(single) = &self.0 {
+            f(single);
+        }
+
+        self
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for TrySingle<'world, 'state, D, F, Desc> {
+    type Target = Result<<Desc::D as WorldQuery>::Item<'world>, SingleError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for TrySingle<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for TrySingle<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for TrySingle<'ww, 'ss, D, F> {
+    type State = QueryState<D, F>;
+    type Item<'w, 's> = TrySingle<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+
+        // SAFETY: This is called exactly once per system run, after world validation
+        let single = unsafe {
+            query_single(state, world, system_last_run(system_meta, world, change_tick), change_tick)
+        };
+
+        TrySingle(single.map_err(SingleError::Query))
+    }
+}
+
+
+/// Single entity from query that may be missing
+/// 
+/// Resolves to `None` if nothing matches, but still panics if several entities match, like [`Single`](crate::Single).
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: OptionalSingle<&Transform, With<Player>>) -> Result<f32, &'static str> {
+///     Ok(player.ok_or("player is not spawned yet")?.translation.y)
+/// }
+/// # let mut world = World::new();
+/// # assert_eq!(world.run_system_once(player_height), Err("player is not spawned yet"));
+/// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+/// # assert_eq!(world.run_system_once(player_height), Ok(3.0));
+/// ```
+/// 
+/// ## Commands
+/// 
+/// Removals and despawns queued through [`Commands`](bevy_ecs::system::Commands) are only seen once they are
+/// applied. Ordering the resolving system after the queueing one makes the schedule apply them in between,
+/// while without that sync point the single still resolves to the former match:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::schedule::ScheduleBuildSettings;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Resource, Default)]
+/// struct PlayerFound(bool);
+/// 
+/// fn demote_player(mut commands: Commands, player: SingleEntity<(), With<Player>>) {
+///     commands.entity(player.0).remove::<Player>();
+/// }
+/// 
+/// fn find_player(player: OptionalSingle<(), With<Player>>, mut found: ResMut<PlayerFound>) {
+///     found.0 = player.is_some();
+/// }
+/// 
+/// fn run(auto_insert_apply_deferred: bool) -> bool {
+///     let mut world = World::new();
+///     world.init_resource::<PlayerFound>();
+///     world.spawn(Player);
+/// 
+///     let mut schedule = Schedule::default();
+///     schedule.set_build_settings(ScheduleBuildSettings { auto_insert_apply_deferred, ..default() });
+///     schedule.add_systems((demote_player, find_player).chain());
+///     schedule.run(&mut world);
+/// 
+///     world.resource::<PlayerFound>().0
+/// }
+/// 
+/// assert!(!run(true));
+/// assert!(run(false));
+/// ```
+pub struct OptionalSingle<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub Option<<Desc::D as WorldQuery>::Item<'world>>);
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> OptionalSingle<'world, 'state, D, F, Desc> {
+    /// Returns the item, or `err` if nothing matched, to be used with `?`
+    pub fn ok_or<E>(self, err: E) -> Result<<Desc::D as WorldQuery>::Item<'world>, E> {
+        self.0.ok_or(err)
+    }
+
+    /// Maps the item with `f`, or returns `default` if nothing matched
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// pub fn player_height(player: OptionalSingle<&Transform, With<Player>>) -> f32 {
+    ///     player.map_or(0.0, |transform| transform.translation.y)
+    /// }
+    /// # let mut world = World::new();
+    /// # assert_eq!(world.run_system_once(player_height), 0.0);
+    /// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+    /// # assert_eq!(world.run_system_once(player_height), 3.0);
+    /// ```
+    pub fn map_or<U>(self, default: U, f: impl FnOnce(<Desc::D as WorldQuery>::Item<'world>) -> U) -> U {
+        self.0.map_or(default, f)
+    }
+
+    /// Maps the item with `f`, or computes a fallback with `default` if nothing matched
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// pub fn player_name(player: OptionalSingle<&Name, With<Player>>) -> String {
+    ///     player.map_or_else(|| String::from("nobody"), |name| name.to_string())
+    /// }
+    /// # let mut world = World::new();
+    /// # assert_eq!(world.run_system_once(player_name), "nobody");
+    /// # world.spawn((Name::new("Player"), Player));
+    /// # assert_eq!(world.run_system_once(player_name), "Player");
+    /// ```
+    pub fn map_or_else<U>(
+        self,
+        default: impl FnOnce() -> U,
+        f: impl FnOnce(<Desc::D as WorldQuery>::Item<'world>) -> U,
+    ) -> U {
+        self.0.map_or_else(default, f)
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for OptionalSingle<'world, 'state, D, F, Desc> {
+    type Target = Option<<Desc::D as WorldQuery>::Item<'world>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for OptionalSingle<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for OptionalSingle<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for OptionalSingle<'ww, 'ss, D, F> {
+    type State = QueryState<D, F>;
+    type Item<'w, 's> = OptionalSingle<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+
+        // SAFETY: Same as `Single`, this is the only item handed out for this run
+        let single = unsafe {
+            match query_single(state, world, system_last_run(system_meta, world, change_tick), change_tick) {
+                Ok(single) => Some(single),
+                Err(QuerySingleError::NoEntities(_)) => None,
+                // Several matches panic with the same message as `Single`
+                Err(QuerySingleError::MultipleEntities(_)) => Some(resolve_single(state, system_meta, world, change_tick, Duplicates::Panic)),
+            }
+        };
+
+        OptionalSingle(single)
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt::Display;
+
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, QuerySingleError, QueryState, ReadOnlyQueryData}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{init_query_state, query_single, system_last_run};
+
+
+/// Single entity from query that is only resolved when asked for, inside the system body
+/// 
+/// Unlike [`Single`](crate::Single) this never panics on its own, leaving the choice between
+/// [`LazySingle::ok`], [`LazySingle::unwrap`] and [`LazySingle::expect`] to the system.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: LazySingle<&Transform, With<Player>>) -> f32 {
+///     player.expect("player should be spawned before this system").translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+/// # assert_eq!(world.run_system_once(player_height), 3.0);
+/// ```
+/// 
+/// The message is used when the single can not be resolved:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: LazySingle<&Transform, With<Player>>) -> f32 {
+///     player.expect("player should be spawned before this system").translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.run_system_once(player_height);
+/// ```
+pub struct LazySingle<'world, 'state, D: QueryData, F: QueryFilter = ()> {
+    query: Query<'world, 'state, D, F>,
+    resolver: &'state QueryState<D, F>,
+    world: UnsafeWorldCell<'world>,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter> LazySingle<'world, 'state, D, F> {
+    fn resolve(self) -> Result<D::Item<'world>, QuerySingleError> {
+        // SAFETY: The resolver has the same access as the query, which was registered in `init_state`.
+        // `self` is consumed with the query, so no other item of it is alive anymore.
+        unsafe { query_single(self.resolver, self.world, self.last_run, self.this_run) }
+    }
+
+    /// Returns the underlying [`Query`], to look at all matches before or instead of resolving the single
+    /// 
+    /// The query has the same access and change ticks as the single. Its items borrow the `LazySingle`,
+    /// so they have to be dropped before the single is resolved, which consumes it.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Enemy;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// 
+    /// pub fn weakest_enemy(mut enemy: LazySingle<&mut Health, With<Enemy>>) -> u32 {
+    ///     let count = enemy.query().iter().count();
+    ///     if count > 1 {
+    ///         return enemy.query().iter().map(|health| health.0).min().unwrap();
+    ///     }
+    /// 
+    ///     let mut health = enemy.unwrap();
+    ///     health.0 += 1;
+    ///     health.0
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Health(5), Enemy));
+    /// # assert_eq!(world.run_system_once(weakest_enemy), 6);
+    /// # world.spawn((Health(3), Enemy));
+    /// # world.spawn((Health(4), Enemy));
+    /// # assert_eq!(world.run_system_once(weakest_enemy), 3);
+    /// ```
+    pub fn query(&mut self) -> &mut Query<'world, 'state, D, F> {
+        &mut self.query
+    }
+
+    /// Resolves the single, returning `None` if there is not exactly one match
+    pub fn ok(self) -> Option<D::Item<'world>> {
+        self.resolve().ok()
+    }
+
+    /// Resolves the single, panicking if there is not exactly one match
+    pub fn unwrap(self) -> D::Item<'world> {
+        self.resolve().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Resolves the single, panicking with `msg` if there is not exactly one match
+    pub fn expect(self, msg: impl Display) -> D::Item<'world> {
+        self.resolve().unwrap_or_else(|error| panic!("{msg}: {error}"))
+    }
+}
+
+
+/// State of [`LazySingle`]
+/// 
+/// The single is resolved through its own copy of the query state, so that the resolved item can outlive
+/// the [`Query`] handed out by [`LazySingle::query`]
+#[doc(hidden)]
+pub struct LazySingleState<D: QueryData, F: QueryFilter> {
+    query: QueryState<D, F>,
+    resolver: QueryState<D, F>,
+}
+
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for LazySingle<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for LazySingle<'ww, 'ss, D, F> {
+    type State = LazySingleState<D, F>;
+    type Item<'w, 's> = LazySingle<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        LazySingleState {
+            query: init_query_state(world, system_meta),
+            resolver: QueryState::new(world),
+        }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(&mut state.query, archetype, system_meta);
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(&mut state.resolver, archetype, system_meta);
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        let LazySingleState { query, resolver } = state;
+        resolver.validate_world(world.id());
+
+        // SAFETY: Forwarded from the caller, the query's access was registered in `init_state`
+        let query = unsafe { <Query<D, F> as SystemParam>::get_param(query, system_meta, world, change_tick) };
+
+        LazySingle {
+            query,
+            resolver,
+            world,
+            last_run: system_last_run(system_meta, world, change_tick),
+            this_run: change_tick,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use core::ops::{Deref, DerefMut};
+
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{init_query_state, resolve_single, Duplicates, SingleDescriptor};
+
+
+/// Get single entity from query, taking the first match if there are several
+/// 
+/// Still panics if nothing matches. Which match comes first is an implementation detail of the query
+/// iteration order, so this is meant for cases where any of the duplicates will do.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// pub fn camera_height(camera: LenientSingle<&Transform, With<MainCamera>>) -> f32 {
+///     camera.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(0.0, 5.0, 0.0), MainCamera));
+/// # world.spawn((Transform::from_xyz(0.0, 5.0, 0.0), MainCamera));
+/// # assert_eq!(world.run_system_once(camera_height), 5.0);
+/// ```
+/// 
+/// With a plain [`Single`](crate::Single) the same world panics:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// pub fn camera_height(camera: Single<&Transform, With<MainCamera>>) -> f32 {
+///     camera.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(0.0, 5.0, 0.0), MainCamera));
+/// # world.spawn((Transform::from_xyz(0.0, 5.0, 0.0), MainCamera));
+/// # world.run_system_once(camera_height);
+/// ```
+pub struct LenientSingle<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub <Desc::D as WorldQuery>::Item<'world>);
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for LenientSingle<'world, 'state, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for LenientSingle<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for LenientSingle<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for LenientSingle<'ww, 'ss, D, F> {
+    type State = QueryState<D, F>;
+    type Item<'w, 's> = LenientSingle<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs.
+        unsafe {
+            LenientSingle(resolve_single(state, system_meta, world, change_tick, Duplicates::First))
+        }
+    }
+}
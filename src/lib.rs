@@ -154,18 +154,139 @@
 //!     // ...
 //! }
 //! ```
+//!
+//! ## Example with hierarchy
+//!
+//! Resolve the root and its children in one lookup, then walk the children with a regular query:
+//! ```rust
+//! # use bevy::prelude::*;
+//! # use bevy::ecs::system::RunSystemOnce;
+//! # use bevy_single::prelude::*;
+//! # #[derive(Component)]
+//! # struct Root;
+//! pub fn follow_root(
+//!     Single((root, children)): Single<(&Transform, &Children), With<Root>>,
+//!     mut transforms: Query<&mut Transform, Without<Root>>,
+//! ) {
+//!     for &child in children.iter() {
+//!         if let Ok(mut transform) = transforms.get_mut(child) {
+//!             transform.translation = root.translation;
+//!         }
+//!     }
+//! }
+//! # let mut world = World::new();
+//! # world.spawn((Transform::from_xyz(1.0, 2.0, 3.0), Root)).with_children(|parent| {
+//! #     parent.spawn(Transform::default());
+//! #     parent.spawn(Transform::default());
+//! # });
+//! # world.run_system_once(follow_root);
+//! # let mut children = world.query_filtered::<&Transform, Without<Root>>();
+//! # assert_eq!(children.iter(&world).count(), 2);
+//! # assert!(children.iter(&world).all(|t| t.translation == Vec3::new(1.0, 2.0, 3.0)));
+//! ```
+//!
+//! ## Bevy compatibility
+//!
+//! | bevy_single | Bevy |
+//! |-------------|------|
+//! | 0.1         | 0.14 |
+//!
+//! ## Features
+//!
+//! - `diagnostics`: adds [`SingleCountDiagnosticPlugin`](crate::SingleCountDiagnosticPlugin), recording how many
+//!   entities match a filter as a Bevy diagnostic.
+//! - `app`: implements [`OrderSingleExt`](crate::OrderSingleExt) for `App` and adds
+//!   [`SingleAssertPlugin`](crate::SingleAssertPlugin).
+//! - `hierarchy`: adds [`WorldSingleExt::despawn_single_recursive`](crate::WorldSingleExt).
+//! - `names`: lists the `Name` of every matched entity when a [`Single`](crate::Single) panics on several
+//!   matches. This registers a read of `Name` for every `Single`, so systems can no longer write `Name`
+//!   next to a `Single` unless its own query data accesses `Name`.
+//! - `render`: adds [`ExtractSingle`](crate::ExtractSingle) for render world extraction systems.
+//! - `state`: adds [`StateScopedSingle`](crate::StateScopedSingle) for singletons tied to a `bevy_state` state.
+//! - `debug-log`: logs the entity every [`SingleEntity`](crate::SingleEntity) resolves to at `debug` level.
+//! - `test-utils`: adds the [`assert_single_err!`](crate::assert_single_err) macro for tests.
+//!
+//! The crate uses `core` and `alloc` paths where it can, but there is no `no_std` support: `bevy_ecs` 0.14
+//! requires `std`, so a `no_std` build of this crate could not be checked or used.
 
-use std::{borrow::Cow, mem, ops::{Deref, DerefMut}};
+extern crate alloc;
 
-use bevy_ecs::{archetype::{Archetype, ArchetypeComponentId}, component::{ComponentId, Tick}, query::{Access, FilteredAccessSet, QueryData, QueryFilter, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt::Debug, hash::{Hash, Hasher}, ops::{Deref, DerefMut}};
 
+#[cfg(feature = "names")]
+use bevy_core::Name;
+use bevy_ecs::{archetype::Archetype, change_detection::{DetectChanges, DetectChangesMut, Ref}, component::{Component, ComponentId, Tick}, entity::Entity, query::{FilteredAccess, QueryData, QueryEntityError, QueryFilter, QueryIter, QuerySingleError, QueryState, ROQueryItem, ReadOnlyQueryData, Without, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemChangeTick, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+
+mod array;
+#[cfg(feature = "app")]
+mod assert;
+mod cached;
+pub mod conditions;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod dynamic;
+mod error;
+mod fallible;
+mod lazy;
+mod lenient;
+mod metered;
+mod or_spawn;
+mod ordering;
+mod owned;
+mod pipe;
+mod prioritized;
+#[cfg(feature = "render")]
+mod render;
+mod selected;
+mod single_entity;
+mod single_or_all;
+#[cfg(feature = "state")]
+mod state;
+#[cfg(feature = "test-utils")]
+mod testing;
+mod with_res;
+mod world;
+
+pub use array::SingleArray;
+#[cfg(feature = "app")]
+pub use assert::SingleAssertPlugin;
+pub use cached::CachedSingleEntity;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::SingleCountDiagnosticPlugin;
+pub use dynamic::{DynamicComponent, DynamicSingle};
+pub use error::SingleError;
+pub use fallible::{OptionalSingle, TrySingle};
+pub use lazy::LazySingle;
+pub use lenient::LenientSingle;
+pub use metered::{MeteredSingle, SingleMetric, SingleMetrics};
+pub use or_spawn::SingleOrSpawn;
+pub use ordering::{ConsumesSingle, OrderSingleExt, SpawnsSingle};
+pub use owned::ToOwnedItem;
+pub use pipe::{pipe_single, run_with_single};
+pub use prioritized::PrioritizedSingle;
+#[cfg(feature = "render")]
+pub use render::ExtractSingle;
+pub use selected::{SelectedSingle, SingleSelector};
+pub use single_entity::SingleEntity;
+pub use single_or_all::SingleOrAll;
+#[cfg(feature = "state")]
+pub use state::StateScopedSingle;
+pub use with_res::SingleWithRes;
+pub use world::{count_matches, CachedSingleState, SingleTuple, WorldSingleExt};
 
 pub mod prelude {
-    pub use super::Single;
+    pub use super::{pipe_single, run_with_single, CachedSingleEntity, ConsumesSingle, LazySingle, LenientSingle, MeteredSingle, OptionalSingle, OrderSingleExt, PrioritizedSingle, SelectedSingle, Single, SingleArray, SingleChanged, SingleEnabled, SingleEntity, SingleMut, SingleOrAll, SingleOrSpawn, SingleRef, SingleSelector, SingleWithRes, SpawnsSingle, TrySingle, WorldSingleExt};
 }
 
 
 /// Helper trait for avoiding unused type parameters and lifetimes without PhantomData field on Single
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not describe a `Single`",
+    label = "invalid `Single` descriptor",
+    note = "Single query data must be a reference, Entity, or a tuple thereof, and the descriptor is usually left as `()`"
+)]
 pub trait SingleDescriptor<'world, 'state, D: QueryData, F: QueryFilter> {
     type D: QueryData;    
 }
@@ -330,83 +451,1895 @@ impl <'world, 'state, D: QueryData, F: QueryFilter> SingleDescriptor<'world, 'st
 ///     // ...
 /// }
 /// ```
-pub struct Single<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub <Desc::D as WorldQuery>::Item<'world>);
-
-
-impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for Single<'world, 'state, D, F, Desc> {
-    type Target = <Desc::D as WorldQuery>::Item<'world>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for Single<'world, 'state, D, F, Desc> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
-    for Single<'w, 's, D, F>
-{
-}
-
-
-// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
-// this Query conflicts with any prior access, a panic will occur.
-unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for Single<'ww, 'ss, D, F> {
-    type State = QueryState<D, F>;
-    type Item<'w, 's> = Single<'w, 's, D, F>;
-
-    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
-        <Query<'ww, 'ss, D, F> as SystemParam>::init_state(world, system_meta)
-    }
-
-    unsafe fn new_archetype(
-        state: &mut Self::State,
-        archetype: &Archetype,
-        system_meta: &mut SystemMeta,
-    ) {
-        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(state, archetype, system_meta)
-    }
-
-    #[inline]
-    unsafe fn get_param<'w, 's>(
-        state: &'s mut Self::State,
-        system_meta: &SystemMeta,
-        world: UnsafeWorldCell<'w>,
-        change_tick: Tick,
-    ) -> Self::Item<'w, 's> {
-        // SAFETY: We have registered all of the query's world accesses,
-        // so the caller ensures that `world` has permission to access any
-        // world data that the query needs.
-        unsafe {
-            state.validate_world(world.id());
-
-            let public_meta: &SystemMetaPublicFields = mem::transmute(system_meta);
-            
-            let single = state.get_single_unchecked_manual(
-                world,
-                public_meta.last_run,
-                change_tick,
-            ).unwrap();
-
-            Single(single)
-        }
-    }
-}
-
-
-struct SystemMetaPublicFields {
-    _name: Cow<'static, str>,
-    _component_access_set: FilteredAccessSet<ComponentId>,
-    _archetype_component_access: Access<ArchetypeComponentId>,
-    _is_send: bool,
-    _has_deferred: bool,
-    last_run: Tick,
-    #[cfg(feature = "trace")]
-    _system_span: Span,
-    #[cfg(feature = "trace")]
-    _commands_span: Span,
+/// 
+/// Two mutable singles over the same component, whose filters can not be proven disjoint, can take turns in
+/// a [`ParamSet`](bevy_ecs::system::ParamSet) as well:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct Target;
+/// pub fn swap_heights(
+///     mut singles: ParamSet<(
+///         Single<&mut Transform, With<Player>>,
+///         Single<&mut Transform, With<Target>>,
+///     )>,
+/// ) {
+///     let player_height = singles.p0().translation.y;
+///     let target_height = std::mem::replace(&mut singles.p1().translation.y, player_height);
+///     singles.p0().translation.y = target_height;
+/// }
+/// # let mut world = World::new();
+/// # let player = world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player)).id();
+/// # let target = world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Target)).id();
+/// # world.run_system_once(swap_heights);
+/// # assert_eq!(world.get::<Transform>(player).unwrap().translation.y, 2.0);
+/// # assert_eq!(world.get::<Transform>(target).unwrap().translation.y, 1.0);
+/// ```
+/// 
+/// ## Derived query data
+/// 
+/// Structs deriving [`QueryData`](bevy_ecs::query::QueryData) work like tuples, for both read-only and
+/// `#[query_data(mutable)]` structs. `Single` then holds the generated `...Item` struct:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::{query::QueryData, system::RunSystemOnce};
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// #[derive(QueryData)]
+/// struct PlayerStats {
+///     name: &'static Name,
+///     health: &'static Health,
+/// }
+/// 
+/// #[derive(QueryData)]
+/// #[query_data(mutable)]
+/// struct PlayerBody {
+///     transform: &'static mut Transform,
+///     health: &'static mut Health,
+/// }
+/// 
+/// fn heal(Single(mut player): Single<PlayerBody, With<Player>>) {
+///     player.health.0 += 10;
+///     player.transform.translation.y += 1.0;
+/// }
+/// 
+/// fn health(player: Single<PlayerStats, With<Player>>) -> u32 {
+///     player.health.0
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Name::new("Player"), Health(50), Transform::default(), Player));
+/// # world.spawn((Name::new("Enemy"), Health(50), Transform::default()));
+/// world.run_system_once(heal);
+/// assert_eq!(world.run_system_once(health), 60);
+/// ```
+/// 
+/// Query data must be a component reference, [`Entity`], a `QueryData` struct, or a tuple thereof.
+/// Plain values are rejected at compile time:
+/// ```rust,compile_fail
+/// # use bevy_single::prelude::*;
+/// fn health(health: Single<u32>) -> u32 {
+///     *health
+/// }
+/// ```
+/// 
+/// ## Filters
+/// 
+/// Any [`QueryFilter`] works as the second parameter, including `Or`, nested tuples and
+/// change filters:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// #[derive(Component)]
+/// struct Boss;
+/// 
+/// #[derive(Component)]
+/// struct Dead;
+/// 
+/// pub fn target_name(target: Single<&Name, (Or<(With<Player>, With<Boss>)>, Without<Dead>, Added<Name>)>) -> String {
+///     target.to_string()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Name::new("Player"), Player, Dead));
+/// # world.spawn((Name::new("Boss"), Boss));
+/// # world.spawn(Name::new("Bystander"));
+/// # assert_eq!(world.run_system_once(target_name), "Boss");
+/// ```
+/// 
+/// Filters deriving [`QueryFilter`] resolve the same as the equivalent inline filter:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::{query::QueryFilter, system::RunSystemOnce};
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct Boss;
+/// # #[derive(Component)]
+/// # struct Dead;
+/// #[derive(QueryFilter)]
+/// struct LivingTarget {
+///     target: Or<(With<Player>, With<Boss>)>,
+///     alive: Without<Dead>,
+/// }
+/// 
+/// pub fn target_name(target: Single<&Name, LivingTarget>) -> String {
+///     target.to_string()
+/// }
+/// 
+/// pub fn inline_target_name(target: Single<&Name, (Or<(With<Player>, With<Boss>)>, Without<Dead>)>) -> String {
+///     target.to_string()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Name::new("Player"), Player, Dead));
+/// # world.spawn((Name::new("Boss"), Boss));
+/// # world.spawn(Name::new("Bystander"));
+/// # assert_eq!(world.run_system_once(target_name), "Boss");
+/// # assert_eq!(world.run_system_once(inline_target_name), "Boss");
+/// ```
+/// 
+/// ## Storage
+/// 
+/// Components stored in sparse sets resolve like table components, both as query data and as filters:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// #[component(storage = "SparseSet")]
+/// struct Targeted;
+/// 
+/// #[derive(Component)]
+/// #[component(storage = "SparseSet")]
+/// struct Charge(u32);
+/// 
+/// fn charge_target(Single((mut charge, mut transform)): Single<(&mut Charge, &mut Transform), With<Targeted>>) {
+///     charge.0 += 1;
+///     transform.translation.x = charge.0 as f32;
+/// }
+/// 
+/// let mut world = World::new();
+/// world.spawn_batch((0..8).map(|_| (Charge(0), Transform::default())));
+/// let target = world.spawn((Charge(0), Transform::default(), Targeted)).id();
+/// 
+/// world.run_system_once(charge_target);
+/// world.run_system_once(charge_target);
+/// assert_eq!(world.get::<Charge>(target).unwrap().0, 2);
+/// assert_eq!(world.get::<Transform>(target).unwrap().translation.x, 2.0);
+/// 
+/// world.entity_mut(target).remove::<Targeted>();
+/// assert!(world.get_single::<&Charge, With<Targeted>>().is_err());
+/// ```
+/// 
+/// ## Resolved entity
+/// 
+/// `Single` only holds the item and has no `entity` method, as it keeps no reference to its param state
+/// to read the resolved [`Entity`] from. Ask for the entity up front instead, either as part of the query
+/// data or through [`SingleEntity`] and [`SingleEntity::entity`]:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_from_data(Single((entity, _transform)): Single<(Entity, &Transform), With<Player>>) -> Entity {
+///     entity
+/// }
+/// 
+/// pub fn player_from_param(player: SingleEntity<&Transform, With<Player>>) -> Entity {
+///     player.entity()
+/// }
+/// # let mut world = World::new();
+/// # let player = world.spawn((Transform::default(), Player)).id();
+/// # assert_eq!(world.run_system_once(player_from_data), player);
+/// # assert_eq!(world.run_system_once(player_from_param), player);
+/// ```
+/// 
+/// ## Dynamic access
+/// 
+/// Query data that reads components dynamically, like [`EntityRef`](bevy_ecs::world::EntityRef), works
+/// as well. Its access covers every component, so it conflicts with any mutable access in the same system:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::world::EntityRef;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// pub fn describe_player(player: Single<EntityRef, With<Player>>) -> (bool, Option<u32>) {
+///     (player.contains::<Transform>(), player.get::<Health>().map(|health| health.0))
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Health(5), Player));
+/// # assert_eq!(world.run_system_once(describe_player), (false, Some(5)));
+/// ```
+/// 
+/// ## Observers
+/// 
+/// `Single` can be used in observer systems like in any other system:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Config {
+///     starting_health: u32,
+/// }
+/// 
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// pub fn give_health(trigger: Trigger<OnAdd, Player>, config: Single<&Config>, mut commands: Commands) {
+///     commands.entity(trigger.entity()).insert(Health(config.starting_health));
+/// }
+/// # let mut world = World::new();
+/// # world.observe(give_health);
+/// # world.spawn(Config { starting_health: 10 });
+/// # let player = world.spawn(Player).id();
+/// # world.flush();
+/// # assert_eq!(world.get::<Health>(player).unwrap().0, 10);
+/// ```
+/// 
+/// Observers keep their own system state, so access conflicts panic when the observer is added and
+/// change detection is relative to the previous trigger of the same observer:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Config {
+///     starting_health: u32,
+/// }
+/// 
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// #[derive(Component)]
+/// struct FreshConfig(bool);
+/// 
+/// pub fn give_health(trigger: Trigger<OnAdd, Player>, config: Single<Ref<Config>>, mut commands: Commands) {
+///     let target = trigger.entity();
+///     commands.entity(target).insert((Health(config.starting_health), FreshConfig(config.is_changed())));
+/// }
+/// # let mut world = World::new();
+/// # world.observe(give_health);
+/// # world.spawn(Config { starting_health: 10 });
+/// # let first = world.spawn(Player).id();
+/// # world.flush();
+/// # let second = world.spawn(Player).id();
+/// # world.flush();
+/// # assert_eq!(world.get::<Health>(first).unwrap().0, 10);
+/// # assert_eq!(world.get::<Health>(second).unwrap().0, 10);
+/// # assert!(world.get::<FreshConfig>(first).unwrap().0);
+/// # assert!(!world.get::<FreshConfig>(second).unwrap().0);
+/// # world.query::<&mut Config>().single_mut(&mut world).starting_health = 20;
+/// # let third = world.spawn(Player).id();
+/// # world.flush();
+/// # assert_eq!(world.get::<Health>(third).unwrap().0, 20);
+/// # assert!(world.get::<FreshConfig>(third).unwrap().0);
+/// ```
+/// 
+/// ## Commands
+/// 
+/// Structural changes through [`Commands`](bevy_ecs::system::Commands) are only queued while the system
+/// runs and applied afterwards with exclusive world access, when the item borrowed by `Single` is gone.
+/// The item stays valid for the whole run, even after queuing changes to its own entity:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::System;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// #[derive(Component)]
+/// struct Dying;
+/// 
+/// pub fn take_hit(mut health: Single<(Entity, &mut Health)>, mut commands: Commands) {
+///     let (entity, health) = &mut *health;
+///     commands.entity(*entity).insert(Dying);
+///     commands.spawn(Health(100));
+///     health.0 -= 10;
+/// }
+/// # let mut world = World::new();
+/// # let entity = world.spawn(Health(50)).id();
+/// # let mut system = IntoSystem::into_system(take_hit);
+/// # system.initialize(&mut world);
+/// # system.run((), &mut world);
+/// # assert_eq!(world.get::<Health>(entity).unwrap().0, 40);
+/// # system.apply_deferred(&mut world);
+/// # assert!(world.get::<Dying>(entity).is_some());
+/// # assert_eq!(world.query::<&Health>().iter(&world).count(), 2);
+/// ```
+/// 
+/// ## Change detection
+/// 
+/// `Single<Ref<T>>` and `Single<&mut T>` report changes relative to the last run of the system, same as
+/// [`Query`]. A system passed to `World::run_system_once` is initialized from scratch on every call, so
+/// it always sees the singleton as changed, while a system that is kept around only sees new changes:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::{RunSystemOnce, System};
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Config(u32);
+/// 
+/// pub fn config_changed(config: Single<Ref<Config>>) -> bool {
+///     config.is_changed()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn(Config(1));
+/// # assert!(world.run_system_once(config_changed));
+/// # assert!(world.run_system_once(config_changed));
+/// # let mut system = IntoSystem::into_system(config_changed);
+/// # system.initialize(&mut world);
+/// # assert!(system.run((), &mut world));
+/// # assert!(!system.run((), &mut world));
+/// # world.query::<&mut Config>().single_mut(&mut world).0 = 2;
+/// # assert!(system.run((), &mut world));
+/// ```
+/// 
+/// The same holds in `FixedUpdate`, which can run several times per frame. Every step only sees the
+/// changes made since the previous step:
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy::time::{TimePlugin, TimeUpdateStrategy};
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Config(u32);
+/// #[derive(Resource, Default)]
+/// struct Seen(Vec<bool>);
+/// 
+/// pub fn track_config(config: Single<Ref<Config>>, mut seen: ResMut<Seen>) {
+///     seen.0.push(config.is_changed());
+/// }
+/// # fn change_on_second_step(mut config: Single<&mut Config>, mut step: Local<u32>) {
+/// #     *step += 1;
+/// #     if *step == 2 {
+/// #         config.0 += 1;
+/// #     }
+/// # }
+/// # let mut app = App::new();
+/// # app.add_plugins(TimePlugin)
+/// #     .insert_resource(Time::<Fixed>::from_seconds(0.1))
+/// #     .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(350)))
+/// #     .init_resource::<Seen>()
+/// #     .add_systems(FixedPreUpdate, change_on_second_step)
+/// #     .add_systems(FixedUpdate, track_config);
+/// # app.world_mut().spawn(Config(0));
+/// # app.update();
+/// # app.update();
+/// # let seen = &app.world().resource::<Seen>().0;
+/// # assert!(seen.len() >= 4);
+/// # assert_eq!(seen[..4], [true, true, false, false]);
+/// ```
+/// 
+/// Combining `Ref<T>` with a `Changed<T>` filter is redundant, as every match is changed, but valid. The
+/// filter and the `Ref` compare against the same ticks, so the single resolves to the one changed entity
+/// among unchanged ones and reports it as changed:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::System;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Config(u32);
+/// pub fn changed_config(config: Single<Ref<Config>, Changed<Config>>) -> (u32, bool) {
+///     (config.0, config.is_changed())
+/// }
+/// # let mut world = World::new();
+/// # world.spawn(Config(1));
+/// # let changed = world.spawn(Config(2)).id();
+/// # world.spawn(Config(3));
+/// # let mut system = IntoSystem::into_system(changed_config);
+/// # system.initialize(&mut world);
+/// # // Treats the spawns as already seen by the system
+/// # system.set_last_run(world.change_tick());
+/// # world.increment_change_tick();
+/// # world.get_mut::<Config>(changed).unwrap().0 = 20;
+/// # assert_eq!(system.run((), &mut world), (20, true));
+/// ```
+/// 
+/// ## SystemState
+/// 
+/// `Single` can be used through [`SystemState`](bevy_ecs::system::SystemState) outside of systems,
+/// e.g. in plugins, exclusive systems and tests:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::SystemState;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// let mut world = World::new();
+/// world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player));
+/// 
+/// let mut read: SystemState<Single<&Transform, With<Player>>> = SystemState::new(&mut world);
+/// assert_eq!(read.get(&world).translation.y, 1.0);
+/// 
+/// let mut write: SystemState<Single<&mut Transform, With<Player>>> = SystemState::new(&mut world);
+/// write.get_mut(&mut world).translation.y = 2.0;
+/// 
+/// assert_eq!(read.get(&world).translation.y, 2.0);
+/// ```
+/// 
+/// ## Registered systems
+/// 
+/// Systems registered with [`World::register_system`] are initialized on their first
+/// [`World::run_system`] call like scheduled systems, and keep their `Single` state between calls:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// 
+/// let mut world = World::new();
+/// let system = world.register_system(player_height);
+/// world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+/// assert_eq!(world.run_system(system).unwrap(), 3.0);
+/// ```
+/// 
+/// Bevy 0.14 has no parameter validation before running a system, so a registered `Single` system still
+/// panics when nothing matches instead of returning an error from `run_system`. Use [`TrySingle`] or
+/// [`OptionalSingle`] where the single may be missing:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: OptionalSingle<&Transform, With<Player>>) -> Option<f32> {
+///     Some(player.0?.translation.y)
+/// }
+/// 
+/// let mut world = World::new();
+/// let system = world.register_system(player_height);
+/// assert_eq!(world.run_system(system).unwrap(), None);
+/// ```
+/// 
+/// ## Nested schedules
+/// 
+/// Schedules run through [`World::run_schedule`], like state transitions, initialize and update their
+/// systems on their own, so `Single` behaves there as in the main schedule. Change detection is
+/// relative to the previous run of the nested schedule:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::schedule::ScheduleLabel;
+/// # use bevy_single::prelude::*;
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct EnterLevel;
+/// 
+/// #[derive(Component)]
+/// struct Level(u32);
+/// 
+/// #[derive(Resource, Default)]
+/// struct Loaded(Vec<u32>);
+/// 
+/// pub fn load_level(level: Single<Ref<Level>>, mut loaded: ResMut<Loaded>) {
+///     if level.is_changed() {
+///         loaded.0.push(level.0);
+///     }
+/// }
+/// 
+/// let mut world = World::new();
+/// world.init_resource::<Loaded>();
+/// world.add_schedule(Schedule::new(EnterLevel));
+/// world.schedule_scope(EnterLevel, |_, schedule| {
+///     schedule.add_systems(load_level);
+/// });
+/// 
+/// world.spawn(Level(1));
+/// world.run_schedule(EnterLevel);
+/// world.run_schedule(EnterLevel);
+/// world.query::<&mut Level>().single_mut(&mut world).0 = 2;
+/// world.run_schedule(EnterLevel);
+/// assert_eq!(world.resource::<Loaded>().0, [1, 2]);
+/// ```
+/// 
+/// ## Sub-apps
+/// 
+/// The state of a `Single` belongs to the [`World`] its system was initialized in, so the same system
+/// function can be added to the main app and to a sub-app (such as the render app), each resolving
+/// against its own world. Reusing one initialized system or `SystemState` with another world panics
+/// with a mismatched `World` message, same as [`Query`]; create one per world instead.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::app::{AppLabel, SubApp};
+/// # use bevy::ecs::schedule::ScheduleLabel;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// #[derive(Resource, Default)]
+/// struct Height(f32);
+/// 
+/// #[derive(AppLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// struct RenderLike;
+/// 
+/// pub fn record_height(player: Single<&Transform, With<Player>>, mut height: ResMut<Height>) {
+///     height.0 = player.translation.y;
+/// }
+/// 
+/// let mut app = App::new();
+/// app.init_resource::<Height>().add_systems(Update, record_height);
+/// app.world_mut().spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player));
+/// 
+/// let mut sub_app = SubApp::new();
+/// sub_app.update_schedule = Some(Update.intern());
+/// sub_app.init_resource::<Height>().add_systems(Update, record_height);
+/// sub_app.world_mut().spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player));
+/// app.insert_sub_app(RenderLike, sub_app);
+/// 
+/// app.update();
+/// assert_eq!(app.world().resource::<Height>().0, 1.0);
+/// assert_eq!(app.sub_app(RenderLike).world().resource::<Height>().0, 2.0);
+/// ```
+/// 
+/// ## Splitting tuples
+/// 
+/// A tuple `Single` can be split into one `Single` per element with `split_tuple`, to reuse helpers
+/// typed on a narrower `Single`. Tuples of up to 8 elements are supported:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// fn heal(mut health: Single<&mut Health, With<Player>>) {
+///     health.0 += 1;
+/// }
+/// 
+/// fn lift(mut transform: Single<&mut Transform, With<Player>>) {
+///     transform.translation.y += 1.0;
+/// }
+/// 
+/// pub fn respawn(player: Single<(&mut Health, &mut Transform), With<Player>>) {
+///     let (health, transform) = player.split_tuple();
+///     heal(health);
+///     lift(transform);
+/// }
+/// # let mut world = World::new();
+/// # let player = world.spawn((Health(0), Transform::default(), Player)).id();
+/// # world.run_system_once(respawn);
+/// # assert_eq!(world.get::<Health>(player).unwrap().0, 1);
+/// # assert_eq!(world.get::<Transform>(player).unwrap().translation.y, 1.0);
+/// ```
+/// 
+/// `borrow_tuple_mut` instead borrows every element mutably at once, keeping the `Single` itself:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Health(u32);
+/// 
+/// #[derive(Component)]
+/// struct Mana(u32);
+/// 
+/// #[derive(Component)]
+/// struct Stamina(u32);
+/// 
+/// pub fn drain_health(mut player: Single<(&mut Health, &mut Mana), With<Player>>) {
+///     let (health, mana) = player.borrow_tuple_mut();
+///     mana.0 += health.0;
+///     health.0 = 0;
+/// }
+/// 
+/// pub fn rest(mut player: Single<(&mut Health, &mut Mana, &mut Stamina), With<Player>>) {
+///     let (health, mana, stamina) = player.borrow_tuple_mut();
+///     health.0 += 1;
+///     mana.0 += 1;
+///     stamina.0 += 1;
+/// }
+/// 
+/// pub fn jump(mut player: Single<(&mut Stamina, &mut Transform, &Health, Entity), With<Player>>) -> Entity {
+///     let (stamina, transform, health, entity) = player.borrow_tuple_mut();
+///     if health.0 > 0 {
+///         stamina.0 -= 1;
+///         transform.translation.y += 1.0;
+///     }
+///     *entity
+/// }
+/// # let mut world = World::new();
+/// # let player = world.spawn((Health(2), Mana(1), Stamina(1), Transform::default(), Player)).id();
+/// # world.run_system_once(drain_health);
+/// # assert_eq!((world.get::<Health>(player).unwrap().0, world.get::<Mana>(player).unwrap().0), (0, 3));
+/// # world.run_system_once(rest);
+/// # assert_eq!(world.get::<Stamina>(player).unwrap().0, 2);
+/// # assert_eq!(world.run_system_once(jump), player);
+/// # assert_eq!(world.get::<Stamina>(player).unwrap().0, 1);
+/// # assert_eq!(world.get::<Transform>(player).unwrap().translation.y, 1.0);
+/// ```
+/// 
+/// ## Parallelism
+/// 
+/// `Single` is a [`ReadOnlySystemParam`] only when its query data is read-only, so systems taking a
+/// mutable `Single` are never run in parallel with other systems accessing the same component:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::ReadOnlySystemParam;
+/// # use bevy_single::prelude::*;
+/// # use static_assertions::{assert_impl_all, assert_not_impl_any};
+/// assert_impl_all!(Single<'static, 'static, &'static Transform>: ReadOnlySystemParam, Send, Sync);
+/// assert_impl_all!(Single<'static, 'static, (Entity, &'static Transform), With<Camera>>: ReadOnlySystemParam);
+/// assert_impl_all!(Single<'static, 'static, &'static mut Transform>: Send, Sync);
+/// assert_not_impl_any!(Single<'static, 'static, &'static mut Transform>: ReadOnlySystemParam);
+/// assert_not_impl_any!(Single<'static, 'static, (&'static Name, &'static mut Transform)>: ReadOnlySystemParam);
+/// ```
+/// 
+/// The executor and Bevy's ambiguity detection see the same access as for a [`Query`], so two singles reading
+/// a component are not reported as ambiguous, while two writing it are:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::schedule::{LogLevel, ScheduleBuildError, ScheduleBuildSettings};
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// fn read_a(_player: Single<&Transform, With<Player>>) {}
+/// fn read_b(_player: Single<&Transform, With<Player>>) {}
+/// fn write_a(_player: Single<&mut Transform, With<Player>>) {}
+/// fn write_b(_player: Single<&mut Transform, With<Player>>) {}
+/// 
+/// fn strict_schedule() -> Schedule {
+///     let mut schedule = Schedule::default();
+///     schedule.set_build_settings(ScheduleBuildSettings {
+///         ambiguity_detection: LogLevel::Error,
+///         ..default()
+///     });
+///     schedule
+/// }
+/// 
+/// let mut world = World::new();
+/// 
+/// let mut reads = strict_schedule();
+/// reads.add_systems((read_a, read_b));
+/// assert!(reads.initialize(&mut world).is_ok());
+/// 
+/// let mut writes = strict_schedule();
+/// writes.add_systems((write_a, write_b));
+/// assert!(matches!(writes.initialize(&mut world), Err(ScheduleBuildError::Ambiguity(_))));
+/// 
+/// let mut ordered = strict_schedule();
+/// ordered.add_systems((write_a, write_b).chain());
+/// assert!(ordered.initialize(&mut world).is_ok());
+/// ```
+/// 
+/// ## Panics
+/// 
+/// Panics if the query matches no entities or more than one. In the latter case the message includes
+/// how many and which entities matched, up to 1000 counted and 16 listed. With the `names` feature each
+/// listed entity comes with its `Name`, if it has one:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// # let mut world = World::new();
+/// # let players: Vec<Entity> = world.spawn_batch([(Transform::default(), Player), (Transform::default(), Player), (Transform::default(), Player)]).collect();
+/// # let panic = catch_unwind(AssertUnwindSafe(|| world.run_system_once(player_height))).unwrap_err();
+/// # let message = panic.downcast_ref::<String>().unwrap();
+/// # assert!(message.contains("Multiple entities (3)"));
+/// # assert!(players.iter().all(|player| message.contains(&format!("{player:?}"))));
+/// ```
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// 
+/// pub fn rename_player(Single(mut name): Single<&mut Name, With<Player>>) {
+///     name.set("Hero");
+/// }
+/// 
+/// let mut world = World::new();
+/// world.spawn((Transform::default(), Name::new("Alice"), Player));
+/// world.spawn((Transform::default(), Name::new("Bob"), Player));
+/// 
+/// let panic = catch_unwind(AssertUnwindSafe(|| world.run_system_once(player_height))).unwrap_err();
+/// let message = panic.downcast_ref::<String>().unwrap();
+/// assert!(message.contains("\"Alice\"") && message.contains("\"Bob\""));
+/// 
+/// // A query writing `Name` itself lists the names through its own access
+/// let panic = catch_unwind(AssertUnwindSafe(|| world.run_system_once(rename_player))).unwrap_err();
+/// let message = panic.downcast_ref::<String>().unwrap();
+/// assert!(message.contains("\"Alice\"") && message.contains("\"Bob\""));
+/// ```
+/// 
+/// In debug builds the first panic for each query type without matches is preceded by a warning hinting
+/// at the usual cause, a system running before the one spawning the singleton. Later panics for the same
+/// query type, in any world, skip it:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy::log::tracing_subscriber::{layer::{Context, SubscriberExt}, Layer, Registry};
+/// # use bevy::utils::tracing::{field::Field, subscriber::with_default, Event, Subscriber};
+/// # use bevy_single::prelude::*;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// # use std::sync::{Arc, Mutex};
+/// # #[derive(Component)]
+/// # struct Player;
+/// struct CollectMessages(Arc<Mutex<Vec<String>>>);
+/// 
+/// impl<S: Subscriber> Layer<S> for CollectMessages {
+///     fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+///         event.record(&mut |_field: &Field, value: &dyn std::fmt::Debug| {
+///             self.0.lock().unwrap().push(format!("{value:?}"));
+///         });
+///     }
+/// }
+/// 
+/// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// 
+/// let messages = Arc::new(Mutex::new(Vec::new()));
+/// let mut world = World::new();
+/// 
+/// with_default(Registry::default().with(CollectMessages(messages.clone())), || {
+///     let panic = catch_unwind(AssertUnwindSafe(|| world.run_system_once(player_height))).unwrap_err();
+///     assert!(panic.downcast_ref::<String>().unwrap().starts_with("No entities fit the query"));
+///     let _ = catch_unwind(AssertUnwindSafe(|| world.run_system_once(player_height)));
+/// });
+/// 
+/// let hints = messages.lock().unwrap().iter().filter(|message| message.contains("order this system after your spawn system")).count();
+/// assert_eq!(hints, usize::from(cfg!(debug_assertions)));
+/// ```
+/// 
+/// A filter matching a huge number of entities by accident stops counting early:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// pub fn any_height(transform: Single<&Transform>) -> f32 {
+///     transform.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn_batch((0..100_000).map(|_| Transform::default()));
+/// # let panic = catch_unwind(AssertUnwindSafe(|| world.run_system_once(any_height))).unwrap_err();
+/// # let message = panic.downcast_ref::<String>().unwrap();
+/// # assert!(message.contains("Multiple entities (more than 1000)"));
+/// # assert!(message.ends_with(" and more"));
+/// ```
+/// 
+/// In debug builds it also panics when the query can never match, because the filter excludes a
+/// component the data requires. An `Or` filter with a branch that can still match only warns:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # use std::panic::{catch_unwind, AssertUnwindSafe};
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// pub fn unreachable(_transform: Single<&mut Transform, Without<Transform>>) {}
+/// 
+/// pub fn player_or_unreachable(_transform: Single<&mut Transform, Or<(With<Player>, Without<Transform>)>>) {}
+/// # let mut world = World::new();
+/// # world.spawn((Transform::default(), Player));
+/// // Release builds only panic when resolving, because nothing matched
+/// let panic = catch_unwind(AssertUnwindSafe(|| world.run_system_once(unreachable))).unwrap_err();
+/// let message = panic.downcast_ref::<String>().unwrap();
+/// assert_eq!(message.contains("can never match"), cfg!(debug_assertions));
+/// 
+/// world.run_system_once(player_or_unreachable);
+/// ```
+pub struct Single<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub <Desc::D as WorldQuery>::Item<'world>);
+
+/// [`Single`] that skips entities marked with the `Disabled` component
+/// 
+/// Same as `Single<D, (F, Without<Disabled>)>`.
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component)]
+/// struct Disabled;
+/// 
+/// pub fn player_height(player: SingleEnabled<&Transform, With<Player>, Disabled>) -> f32 {
+///     player.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player));
+/// # world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player, Disabled));
+/// # assert_eq!(world.run_system_once(player_height), 1.0);
+/// ```
+/// 
+/// The same world panics with a plain [`Single`]:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct Disabled;
+/// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player));
+/// # world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player, Disabled));
+/// # world.run_system_once(player_height);
+/// ```
+pub type SingleEnabled<'world, 'state, D, F, Disabled> = Single<'world, 'state, D, (F, Without<Disabled>)>;
+
+/// [`Single`] over a shared reference to the component `T`, mirroring [`Res`](bevy_ecs::system::Res)
+/// 
+/// Same as `Single<&T, F>`.
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: SingleRef<Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player));
+/// # assert_eq!(world.run_system_once(player_height), 2.0);
+/// ```
+pub type SingleRef<'world, 'state, T, F = ()> = Single<'world, 'state, &'static T, F>;
+
+/// [`Single`] over a mutable reference to the component `T`, mirroring [`ResMut`](bevy_ecs::system::ResMut)
+/// 
+/// Same as `Single<&mut T, F>`.
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn jump(mut player: SingleMut<Transform, With<Player>>) {
+///     player.translation.y += 1.0;
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::default(), Player));
+/// # world.run_system_once(jump);
+/// # assert_eq!(world.query::<&Transform>().single(&world).translation.y, 1.0);
+/// ```
+pub type SingleMut<'world, 'state, T, F = ()> = Single<'world, 'state, &'static mut T, F>;
+
+/// [`Single`] over a [`Ref`] to the component `T`, for reading it together with its change ticks
+/// 
+/// Same as `Single<Ref<T>, F>`.
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::System;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_moved(player: SingleChanged<Transform, With<Player>>) -> bool {
+///     player.is_changed()
+/// }
+/// # let mut world = World::new();
+/// # let player = world.spawn((Transform::default(), Player)).id();
+/// # let mut system = IntoSystem::into_system(player_moved);
+/// # system.initialize(&mut world);
+/// # assert!(system.run((), &mut world));
+/// # assert!(!system.run((), &mut world));
+/// # world.get_mut::<Transform>(player).unwrap().translation.x += 1.0;
+/// # assert!(system.run((), &mut world));
+/// ```
+pub type SingleChanged<'world, 'state, T, F = ()> = Single<'world, 'state, Ref<'static, T>, F>;
+
+
+impl<'world, 'state, D: QueryData + 'static, F: QueryFilter + 'static> Single<'world, 'state, D, F> {
+    /// Resolves the single directly from `world`, with change detection between the given ticks
+    /// 
+    /// Systems take `last_run` and `this_run` from their [`SystemMeta`] and the world. Passing them
+    /// explicitly makes [`Changed`](bevy_ecs::query::Changed), [`Added`](bevy_ecs::query::Added) and
+    /// [`Ref`] deterministic in tests, without running a schedule. Panics like a `Single` param if there
+    /// is not exactly one match.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Config(u32);
+    /// 
+    /// let mut world = World::new();
+    /// let before = world.change_tick();
+    /// world.increment_change_tick();
+    /// world.spawn(Config(1));
+    /// let now = world.change_tick();
+    /// 
+    /// let config = Single::<&Config, Changed<Config>>::from_world_with_ticks(&mut world, before, now);
+    /// assert_eq!(config.0, 1);
+    /// 
+    /// assert!(Single::<Ref<Config>>::from_world_with_ticks(&mut world, before, now).is_changed());
+    /// assert!(!Single::<Ref<Config>>::from_world_with_ticks(&mut world, now, now).is_changed());
+    /// ```
+    /// 
+    /// Nothing changed since `last_run`, so the `Changed` filter matches nothing:
+    /// ```rust,should_panic
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Config(u32);
+    /// # let mut world = World::new();
+    /// # world.spawn(Config(1));
+    /// let now = world.change_tick();
+    /// Single::<&Config, Changed<Config>>::from_world_with_ticks(&mut world, now, now);
+    /// ```
+    pub fn from_world_with_ticks(world: &'world mut World, last_run: Tick, this_run: Tick) -> Self {
+        let state = world.query_filtered::<D, F>();
+
+        // SAFETY: `world` is borrowed mutably for the lifetime of the item, so nothing else accesses it.
+        // The state was created from this world and its archetypes are up to date.
+        let single = unsafe { query_single(&state, world.as_unsafe_world_cell(), last_run, this_run) };
+
+        Single(single.unwrap_or_else(|error| panic!("{error}")))
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Single<'world, 'state, D, F, Desc> {
+    /// Returns the resolved item itself, without going through further `Deref` impls of the item
+    pub fn as_item(&self) -> &<Desc::D as WorldQuery>::Item<'world> {
+        &self.0
+    }
+
+    /// Returns the resolved item itself mutably, e.g. to call [`Mut`](bevy_ecs::change_detection::Mut) methods
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Config(u32);
+    /// 
+    /// pub fn touch_config(mut config: Single<&mut Config>) {
+    ///     config.as_item_mut().set_changed();
+    /// }
+    /// # let mut world = World::new();
+    /// # let entity = world.spawn(Config(1)).id();
+    /// # let before = world.entity(entity).get_ref::<Config>().unwrap().last_changed();
+    /// # world.run_system_once(touch_config);
+    /// # assert_ne!(world.entity(entity).get_ref::<Config>().unwrap().last_changed(), before);
+    /// ```
+    pub fn as_item_mut(&mut self) -> &mut <Desc::D as WorldQuery>::Item<'world> {
+        &mut self.0
+    }
+
+    /// Panics with `msg` unless `predicate` holds for the resolved item, returning `self` for chaining
+    /// 
+    /// ```rust,should_panic
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+    ///     player.assert(|t| t.translation.is_finite(), "NaN player position").translation.y
+    /// }
+    /// # let mut world = World::new();
+    /// # let player = world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player)).id();
+    /// # assert_eq!(world.run_system_once(player_height), 2.0);
+    /// # world.get_mut::<Transform>(player).unwrap().translation.y = f32::NAN;
+    /// # world.run_system_once(player_height);
+    /// ```
+    #[track_caller]
+    pub fn assert(&self, predicate: impl Fn(&<Desc::D as WorldQuery>::Item<'world>) -> bool, msg: &str) -> &Self {
+        if !predicate(&self.0) {
+            panic!("{msg}");
+        }
+
+        self
+    }
+
+    /// Runs `f` on the resolved item, e.g. for logging, and returns `self` for chaining
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Resource, Default)]
+    /// struct Seen(Vec<Vec3>);
+    /// 
+    /// pub fn player_height(player: Single<&Transform, With<Player>>, mut seen: ResMut<Seen>) -> Option<f32> {
+    ///     player
+    ///         .inspect(|transform| seen.0.push(transform.translation))
+    ///         .as_option()
+    ///         .map(|transform| transform.translation.y)
+    /// }
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Seen>();
+    /// # world.spawn((Transform::from_xyz(1.0, 2.0, 3.0), Player));
+    /// # assert_eq!(world.run_system_once(player_height), Some(2.0));
+    /// # assert_eq!(world.resource::<Seen>().0, [Vec3::new(1.0, 2.0, 3.0)]);
+    /// ```
+    pub fn inspect(self, f: impl FnOnce(&<Desc::D as WorldQuery>::Item<'world>)) -> Self {
+        f(&self.0);
+        self
+    }
+
+    /// Returns the resolved item as `Some`, to share code paths with [`OptionalSingle`]
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// fn height_or_ground(transform: Option<&Transform>) -> f32 {
+    ///     transform.map_or(0.0, |transform| transform.translation.y)
+    /// }
+    /// 
+    /// pub fn player_height(player: Single<&Transform, With<Player>>) -> f32 {
+    ///     height_or_ground(player.as_option())
+    /// }
+    /// 
+    /// pub fn camera_height(camera: OptionalSingle<&Transform, With<Camera>>) -> f32 {
+    ///     height_or_ground(camera.0)
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player));
+    /// # assert_eq!(world.run_system_once(player_height), 2.0);
+    /// # assert_eq!(world.run_system_once(camera_height), 0.0);
+    /// ```
+    pub fn as_option(self) -> Option<<Desc::D as WorldQuery>::Item<'world>> {
+        Some(self.0)
+    }
+
+    /// Converts into an [`OptionalSingle`] that is always `Some`, for functions written against `OptionalSingle`
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::{query::QueryFilter, system::RunSystemOnce};
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// fn name_or_unknown<F: QueryFilter>(name: OptionalSingle<&Name, F>) -> String {
+    ///     name.map_or_else(|| String::from("unknown"), |name| name.to_string())
+    /// }
+    /// 
+    /// pub fn player_name(player: Single<&Name, With<Player>>) -> String {
+    ///     name_or_unknown(player.maybe())
+    /// }
+    /// 
+    /// pub fn camera_name(camera: OptionalSingle<&Name, With<Camera>>) -> String {
+    ///     name_or_unknown(camera)
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Name::new("Hero"), Player));
+    /// # assert_eq!(world.run_system_once(player_name), "Hero");
+    /// # assert_eq!(world.run_system_once(camera_name), "unknown");
+    /// ```
+    pub fn maybe(self) -> OptionalSingle<'world, 'state, D, F, Desc> {
+        OptionalSingle(Some(self.0))
+    }
+
+    /// Combines this single with another one into a tuple of both items
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(Component)]
+    /// # struct MainCamera;
+    /// fn distance((player, camera): (&Transform, &Transform)) -> f32 {
+    ///     player.translation.distance(camera.translation)
+    /// }
+    /// 
+    /// pub fn camera_distance(
+    ///     player: Single<&Transform, With<Player>>,
+    ///     camera: Single<&Transform, With<MainCamera>>,
+    /// ) -> f32 {
+    ///     distance(player.zip(camera))
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Transform::from_xyz(0.0, 3.0, 0.0), Player));
+    /// # world.spawn((Transform::from_xyz(0.0, 3.0, 4.0), MainCamera));
+    /// # assert_eq!(world.run_system_once(camera_distance), 4.0);
+    /// ```
+    pub fn zip<'w2, 's2, D2: QueryData, F2: QueryFilter, Desc2: SingleDescriptor<'w2, 's2, D2, F2>>(
+        self,
+        other: Single<'w2, 's2, D2, F2, Desc2>,
+    ) -> (<Desc::D as WorldQuery>::Item<'world>, <Desc2::D as WorldQuery>::Item<'w2>) {
+        (self.0, other.0)
+    }
+
+    /// Reads an [`Entity`] from the resolved item and fetches that exact entity from `query`
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Weapon(Entity);
+    /// 
+    /// #[derive(Component)]
+    /// struct Damage(u32);
+    /// 
+    /// pub fn player_damage(player: Single<&Weapon, With<Player>>, weapons: Query<&Damage>) -> u32 {
+    ///     player.follow(&weapons, |weapon| weapon.0).unwrap().0
+    /// }
+    /// # let mut world = World::new();
+    /// # let sword = world.spawn(Damage(7)).id();
+    /// # world.spawn(Damage(3));
+    /// # world.spawn((Weapon(sword), Player));
+    /// # assert_eq!(world.run_system_once(player_damage), 7);
+    /// ```
+    pub fn follow<'q, D2: QueryData, F2: QueryFilter>(
+        &self,
+        query: &'q Query<'_, '_, D2, F2>,
+        field: impl FnOnce(&<Desc::D as WorldQuery>::Item<'world>) -> Entity,
+    ) -> Result<ROQueryItem<'q, D2>, QueryEntityError> {
+        query.get(field(&self.0))
+    }
+
+    /// Mutable version of [`Single::follow`]
+    pub fn follow_mut<'q, D2: QueryData, F2: QueryFilter>(
+        &self,
+        query: &'q mut Query<'_, '_, D2, F2>,
+        field: impl FnOnce(&<Desc::D as WorldQuery>::Item<'world>) -> Entity,
+    ) -> Result<D2::Item<'q>, QueryEntityError> {
+        query.get_mut(field(&self.0))
+    }
+
+    /// Clones every component of the resolved item into an owned value or tuple, up to 4 elements
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Health(u32);
+    /// 
+    /// pub fn snapshot(player: Single<(&Transform, &Health), With<Player>>) -> (Transform, Health) {
+    ///     player.to_owned_tuple()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Transform::from_xyz(1.0, 0.0, 0.0), Health(3), Player));
+    /// # assert_eq!(world.run_system_once(snapshot), (Transform::from_xyz(1.0, 0.0, 0.0), Health(3)));
+    /// ```
+    /// 
+    /// Works the same for wider tuples:
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component, Clone, PartialEq, Debug)]
+    /// # struct Health(u32);
+    /// pub fn snapshot(player: Single<(&Transform, &Health, &Name)>) -> (Transform, Health, Name) {
+    ///     player.to_owned_tuple()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Transform::default(), Health(3), Name::new("Player")));
+    /// # assert_eq!(world.run_system_once(snapshot), (Transform::default(), Health(3), Name::new("Player")));
+    /// ```
+    pub fn to_owned_tuple(&self) -> <<Desc::D as WorldQuery>::Item<'world> as ToOwnedItem>::Owned
+    where
+        <Desc::D as WorldQuery>::Item<'world>: ToOwnedItem,
+    {
+        self.0.to_owned_item()
+    }
+}
+
+macro_rules! impl_tuple_methods {
+    ($($name:ident),*) => {
+        impl<'world, 'state, $($name: QueryData,)* F: QueryFilter> Single<'world, 'state, ($($name,)*), F> {
+            /// Splits the resolved tuple into one [`Single`] per element, keeping the filter `F`
+            /// 
+            /// Each part owns its piece of the item, so the parts can be passed independently to
+            /// functions typed on `Single` for the rest of the system. They can not outlive the system run.
+            #[allow(non_snake_case)]
+            pub fn split_tuple(self) -> ($(Single<'world, 'state, $name, F>,)*) {
+                let ($($name,)*) = self.0;
+                ($(Single($name),)*)
+            }
+
+            /// Borrows every element of the resolved tuple mutably at once
+            #[allow(non_snake_case)]
+            pub fn borrow_tuple_mut(&mut self) -> ($(&mut <$name as WorldQuery>::Item<'world>,)*) {
+                let ($($name,)*) = &mut self.0;
+                ($($name,)*)
+            }
+        }
+    };
+}
+
+impl_tuple_methods!(D0, D1);
+impl_tuple_methods!(D0, D1, D2);
+impl_tuple_methods!(D0, D1, D2, D3);
+impl_tuple_methods!(D0, D1, D2, D3, D4);
+impl_tuple_methods!(D0, D1, D2, D3, D4, D5);
+impl_tuple_methods!(D0, D1, D2, D3, D4, D5, D6);
+impl_tuple_methods!(D0, D1, D2, D3, D4, D5, D6, D7);
+
+impl<'world, 'state, 'a, T: Component, F: QueryFilter> Single<'world, 'state, &'a T, F> {
+    /// Returns the component as a slice, for collection-like components
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Waypoints(Vec<Vec2>);
+    /// 
+    /// impl AsRef<[Vec2]> for Waypoints {
+    ///     fn as_ref(&self) -> &[Vec2] {
+    ///         &self.0
+    ///     }
+    /// }
+    /// 
+    /// pub fn next_waypoint(waypoints: Single<&Waypoints>) -> Option<Vec2> {
+    ///     waypoints.as_slice().first().copied()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn(Waypoints(vec![Vec2::X, Vec2::Y]));
+    /// # assert_eq!(world.run_system_once(next_waypoint), Some(Vec2::X));
+    /// ```
+    pub fn as_slice<U>(&self) -> &'world [U]
+    where
+        T: AsRef<[U]>,
+    {
+        <T as AsRef<[U]>>::as_ref(self.0)
+    }
+}
+
+impl<'world, 'state, 'a, A, B, T: Component + Deref<Target = (A, B)>, F: QueryFilter> Single<'world, 'state, &'a T, F> {
+    /// Borrows both halves of a component wrapping a pair
+    /// 
+    /// This is about the component's own value. For tuple query data, see [`Single::split_tuple`].
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component, Deref)]
+    /// struct Bounds((Vec2, Vec2));
+    /// 
+    /// pub fn bounds_size(bounds: Single<&Bounds>) -> Vec2 {
+    ///     let (min, max) = bounds.unzip();
+    ///     *max - *min
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn(Bounds((Vec2::new(1.0, 1.0), Vec2::new(4.0, 3.0))));
+    /// # assert_eq!(world.run_system_once(bounds_size), Vec2::new(3.0, 2.0));
+    /// ```
+    pub fn unzip(&self) -> (&A, &B) {
+        let (a, b) = &**self.0;
+        (a, b)
+    }
+}
+
+impl<'world, 'state, 'a, D: QueryData, T: Component, F: QueryFilter> Single<'world, 'state, (D, Option<&'a T>), F> {
+    /// Returns the optional second component of a `Single<(D, Option<&T>)>`, `None` if the entity lacks it
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Shield(u32);
+    /// 
+    /// pub fn player_defense(player: Single<(&Transform, Option<&Shield>), With<Player>>) -> u32 {
+    ///     player.optional().map_or(0, |shield| shield.0)
+    /// }
+    /// # let mut world = World::new();
+    /// # let player = world.spawn((Transform::default(), Player)).id();
+    /// # assert_eq!(world.run_system_once(player_defense), 0);
+    /// # world.entity_mut(player).insert(Shield(4));
+    /// # assert_eq!(world.run_system_once(player_defense), 4);
+    /// ```
+    pub fn optional(&self) -> Option<&'world T> {
+        self.0.1
+    }
+}
+
+impl<'world, 'state, 'a, T: Component, F: QueryFilter> Single<'world, 'state, Ref<'a, T>, F> {
+    /// Calls `f` with the component only if it changed since the last run of the system
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::System;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Config(u32);
+    /// 
+    /// pub fn apply_config(config: Single<Ref<Config>>, mut applied: Local<Vec<u32>>) -> Vec<u32> {
+    ///     config.if_changed(|config| applied.push(config.0));
+    ///     applied.clone()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn(Config(1));
+    /// # let mut system = IntoSystem::into_system(apply_config);
+    /// # system.initialize(&mut world);
+    /// # assert_eq!(system.run((), &mut world), [1]);
+    /// # assert_eq!(system.run((), &mut world), [1]);
+    /// # world.query::<&mut Config>().single_mut(&mut world).0 = 2;
+    /// # assert_eq!(system.run((), &mut world), [1, 2]);
+    /// # assert_eq!(system.run((), &mut world), [1, 2]);
+    /// ```
+    pub fn if_changed(&self, f: impl FnOnce(&T)) {
+        if self.0.is_changed() {
+            f(&self.0);
+        }
+    }
+}
+
+impl<'world, 'state, 'a, T: Component, F: QueryFilter> Single<'world, 'state, &'a mut T, F> {
+    /// Calls `f` with the component only if it changed since the last run of the system
+    /// 
+    /// Does not mark the component as changed itself.
+    pub fn if_changed(&self, f: impl FnOnce(&T)) {
+        if self.0.is_changed() {
+            f(&self.0);
+        }
+    }
+
+    /// Overwrites the component with `value` only if it differs, returning `true` if it was written
+    /// 
+    /// Same as [`DetectChangesMut::set_if_neq`], so an equal value does not mark the component as changed.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::System;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component, PartialEq)]
+    /// enum Mode {
+    ///     Walk,
+    ///     Run,
+    /// }
+    /// 
+    /// #[derive(Resource)]
+    /// struct Running(bool);
+    /// 
+    /// pub fn update_mode(mut mode: Single<&mut Mode>, running: Res<Running>) -> bool {
+    ///     mode.set_if_neq(if running.0 { Mode::Run } else { Mode::Walk })
+    /// }
+    /// 
+    /// pub fn mode_changed(mode: Single<Ref<Mode>>) -> bool {
+    ///     mode.is_changed()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn(Mode::Walk);
+    /// # world.insert_resource(Running(false));
+    /// # let mut update = IntoSystem::into_system(update_mode);
+    /// # let mut changed = IntoSystem::into_system(mode_changed);
+    /// # update.initialize(&mut world);
+    /// # changed.initialize(&mut world);
+    /// # assert!(changed.run((), &mut world));
+    /// # assert!(!update.run((), &mut world));
+    /// # assert!(!changed.run((), &mut world));
+    /// # world.insert_resource(Running(true));
+    /// # assert!(update.run((), &mut world));
+    /// # assert!(changed.run((), &mut world));
+    /// ```
+    pub fn set_if_neq(&mut self, value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.set_if_neq(value)
+    }
+
+    /// Marks the component as changed without writing it, e.g. to make `Changed` systems run again
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::System;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Config(u32);
+    /// 
+    /// pub fn refresh_config(mut config: Single<&mut Config>) {
+    ///     config.trigger_change();
+    /// }
+    /// 
+    /// pub fn config_changed(config: Single<Ref<Config>>) -> bool {
+    ///     config.is_changed()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn(Config(1));
+    /// # let mut refresh = IntoSystem::into_system(refresh_config);
+    /// # let mut changed = IntoSystem::into_system(config_changed);
+    /// # refresh.initialize(&mut world);
+    /// # changed.initialize(&mut world);
+    /// # assert!(changed.run((), &mut world));
+    /// # assert!(!changed.run((), &mut world));
+    /// # refresh.run((), &mut world);
+    /// # assert!(changed.run((), &mut world));
+    /// # assert_eq!(world.query::<&Config>().single(&world).0, 1);
+    /// ```
+    pub fn trigger_change(&mut self) {
+        self.0.set_changed();
+    }
+
+    /// Returns the component immutably, without marking it changed
+    /// 
+    /// Reading through a `mut` binding can pick `DerefMut` by accident, e.g. when calling a `&mut self`
+    /// method only to read. This never does.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::System;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// 
+    /// pub fn heal(mut health: Single<&mut Health>) -> u32 {
+    ///     let current = health.shared().0;
+    ///     if current < 50 {
+    ///         health.0 = 50;
+    ///     }
+    ///     current
+    /// }
+    /// 
+    /// pub fn health_changed(health: Single<Ref<Health>>) -> bool {
+    ///     health.is_changed()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn(Health(80));
+    /// # let mut heal = IntoSystem::into_system(heal);
+    /// # let mut changed = IntoSystem::into_system(health_changed);
+    /// # heal.initialize(&mut world);
+    /// # changed.initialize(&mut world);
+    /// # assert!(changed.run((), &mut world));
+    /// # assert_eq!(heal.run((), &mut world), 80);
+    /// # assert!(!changed.run((), &mut world));
+    /// # world.query::<&mut Health>().single_mut(&mut world).0 = 10;
+    /// # assert!(changed.run((), &mut world));
+    /// # assert_eq!(heal.run((), &mut world), 10);
+    /// # assert!(changed.run((), &mut world));
+    /// ```
+    pub fn shared(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Read-only singles compare and hash by the referenced component
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::SystemState;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Version(u32);
+/// 
+/// let mut old_world = World::new();
+/// old_world.spawn(Version(1));
+/// let mut new_world = World::new();
+/// new_world.spawn(Version(2));
+/// 
+/// let mut old_state: SystemState<Single<&Version>> = SystemState::new(&mut old_world);
+/// let mut new_state: SystemState<Single<&Version>> = SystemState::new(&mut new_world);
+/// let (old, new) = (old_state.get(&old_world), new_state.get(&new_world));
+/// 
+/// assert!(old < new);
+/// assert_eq!(old.cmp(&new), std::cmp::Ordering::Less);
+/// assert!(old != new);
+/// ```
+impl<'world, 'state, 'a, T: Component + PartialEq, F: QueryFilter> PartialEq for Single<'world, 'state, &'a T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl<'world, 'state, 'a, T: Component + Eq, F: QueryFilter> Eq for Single<'world, 'state, &'a T, F> {}
+
+impl<'world, 'state, 'a, T: Component + PartialOrd, F: QueryFilter> PartialOrd for Single<'world, 'state, &'a T, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (*self.0).partial_cmp(other.0)
+    }
+}
+
+impl<'world, 'state, 'a, T: Component + Ord, F: QueryFilter> Ord for Single<'world, 'state, &'a T, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self.0).cmp(other.0)
+    }
+}
+
+impl<'world, 'state, 'a, T: Component + Hash, F: QueryFilter> Hash for Single<'world, 'state, &'a T, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self.0).hash(state);
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for Single<'world, 'state, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for Single<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for Single<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for Single<'ww, 'ss, D, F> {
+    type State = SingleState<D, F>;
+    type Item<'w, 's> = Single<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let query = init_query_state(world, system_meta);
+
+        #[cfg(feature = "names")]
+        let names = {
+            let name = world.init_component::<Name>();
+
+            // A query accessing `Name` itself reads the names through its own access instead, so
+            // `Single<&mut Name>` does not conflict with itself
+            (!query.component_access().access().has_read(name))
+                .then(|| <Query<Option<&Name>> as SystemParam>::init_state(world, system_meta))
+        };
+
+        SingleState {
+            query,
+            #[cfg(feature = "names")]
+            names,
+        }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(&mut state.query, archetype, system_meta);
+
+        #[cfg(feature = "names")]
+        if let Some(names) = &mut state.names {
+            <Query<'ww, 'ss, Option<&Name>> as SystemParam>::new_archetype(names, archetype, system_meta);
+        }
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        #[cfg(feature = "names")]
+        {
+            state.query.validate_world(world.id());
+            let last_run = system_last_run(system_meta, world, change_tick);
+
+            // SAFETY: We have registered all of the query's world accesses, and the read of `Name` unless
+            // the query reads it itself, so the caller ensures that `world` has permission to access them.
+            unsafe {
+                match query_single(&state.query, world, last_run, change_tick) {
+                    Ok(single) => return Single(single),
+                    Err(QuerySingleError::MultipleEntities(query)) => {
+                        let mut matches = <Query<D, F> as SystemParam>::get_param(&mut state.query, system_meta, world, change_tick);
+
+                        match &state.names {
+                            Some(names) => {
+                                let mut entities = matches.transmute_lens_filtered::<Entity, F>();
+                                let named = entities.query().iter().map(|entity| {
+                                    (entity, names.get_unchecked_manual(world, entity, last_run, change_tick).ok().flatten())
+                                });
+
+                                multiple_entities_panic(query, named)
+                            }
+                            None => multiple_entities_panic(query, matches.transmute_lens_filtered::<(Entity, Option<&Name>), F>().query().iter()),
+                        }
+                    }
+                    // Resolved again below, only to panic with the same message as without names
+                    Err(_) => {}
+                }
+            }
+        }
+
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs.
+        unsafe {
+            Single(resolve_single(&mut state.query, system_meta, world, change_tick, Duplicates::Panic))
+        }
+    }
+}
+
+
+/// State of [`Single`]
+#[doc(hidden)]
+pub struct SingleState<D: QueryData, F: QueryFilter> {
+    query: QueryState<D, F>,
+    /// Reads the names listed when several entities match, unless the query reads `Name` itself
+    #[cfg(feature = "names")]
+    names: Option<QueryState<Option<&'static Name>>>,
+}
+
+
+/// Initializes the query state of a variant the same way [`Query`] does
+///
+/// In debug builds this also panics if the query can never match because a component required by the
+/// data is excluded by the filter, like in `Single<&mut Transform, Without<Transform>>`. That is only the
+/// case if every filter set of the query excludes the component, so an `Or` filter with another branch
+/// that can match is only warned about.
+pub(crate) fn init_query_state<D: QueryData + 'static, F: QueryFilter + 'static>(
+    world: &mut World,
+    system_meta: &mut SystemMeta,
+) -> QueryState<D, F> {
+    let state = <Query<D, F> as SystemParam>::init_state(world, system_meta);
+
+    if cfg!(debug_assertions) {
+        let access = state.component_access();
+
+        for id in access.access().reads_and_writes() {
+            // Only flattened filters are public, so these are checked per filter set below
+            if !access.with_filters().any(|with| with == id) || !access.without_filters().any(|without| without == id) {
+                continue;
+            }
+
+            let name = world.components().get_name(id).unwrap_or("<unknown>");
+
+            // Access conflicting with the query is compatible only if every filter set of the query is
+            // ruled out by the probe's single filter set
+            let every_set = |filter: fn(&mut FilteredAccess<ComponentId>, ComponentId)| {
+                let mut probe = FilteredAccess::default();
+                probe.add_write(id);
+                filter(&mut probe, id);
+                access.is_compatible(&probe)
+            };
+
+            let always_excluded = every_set(FilteredAccess::and_with) && every_set(FilteredAccess::and_without);
+
+            assert!(
+                !always_excluded,
+                "Query {} can never match: {name} is required by the query data and excluded by the filter",
+                core::any::type_name::<QueryState<D, F>>(),
+            );
+
+            bevy_utils::tracing::warn!(
+                "Query {} only matches some of its filters: {name} is required by the query data and excluded by one of them",
+                core::any::type_name::<QueryState<D, F>>(),
+            );
+        }
+    }
+
+    state
+}
+
+
+/// What resolution does when more than one entity matches
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Duplicates {
+    /// Panic, reporting how many entities matched
+    Panic,
+    /// Take the first match in iteration order
+    First,
+}
+
+
+/// Matches counted at most for the panic message of [`resolve_single`] and [`SingleArray`]
+pub(crate) const REPORTED_MATCHES: usize = 1000;
+
+/// Matched entities listed at most in the panic message of [`resolve_single`] and [`SingleArray`]
+pub(crate) const LISTED_MATCHES: usize = 16;
+
+/// Resolves the single item matched by `state`, panicking with a descriptive message if there is none,
+/// and handling multiple matches according to `duplicates`
+///
+/// # Safety
+/// Same as [`SystemParam::get_param`] of a [`Query`] using this `state`
+pub(crate) unsafe fn resolve_single<'w, D: QueryData + 'static, F: QueryFilter + 'static>(
+    state: &mut QueryState<D, F>,
+    system_meta: &SystemMeta,
+    world: UnsafeWorldCell<'w>,
+    change_tick: Tick,
+    duplicates: Duplicates,
+) -> D::Item<'w> {
+    state.validate_world(world.id());
+    let last_run = system_last_run(system_meta, world, change_tick);
+
+    match query_single(state, world, last_run, change_tick) {
+        Ok(single) => single,
+        Err(QuerySingleError::MultipleEntities(query)) => {
+            if duplicates == Duplicates::First {
+                // The query matched, so there is a first item
+                return query_iter(state, world, last_run, change_tick).next().unwrap();
+            }
+
+            let mut matches = <Query<D, F> as SystemParam>::get_param(state, system_meta, world, change_tick);
+
+            multiple_entities_panic(query, matches.transmute_lens_filtered::<Entity, F>().query().iter())
+        }
+        Err(error) => {
+            if let QuerySingleError::NoEntities(_) = error {
+                hint_no_entities::<D, F>();
+            }
+
+            panic!("{error}")
+        }
+    }
+}
+
+
+/// Panics for a query that matched several entities, listing the first `matches`
+///
+/// The matches are collected only here, so successful resolution does not pay for them. Iteration stops
+/// past the reported count, so a filter matching millions of entities does not stall the panic.
+fn multiple_entities_panic<T: Debug>(query: &str, matches: impl Iterator<Item = T>) -> ! {
+    let entities: Vec<T> = matches.take(REPORTED_MATCHES + 1).collect();
+    let listed = &entities[..entities.len().min(LISTED_MATCHES)];
+    let more = if entities.len() > LISTED_MATCHES { " and more" } else { "" };
+
+    if entities.len() > REPORTED_MATCHES {
+        panic!("Multiple entities (more than {REPORTED_MATCHES}) fit the query {query}: {listed:?}{more}")
+    }
+
+    panic!("Multiple entities ({}) fit the query {query}: {listed:?}{more}", entities.len())
+}
+
+
+/// Warns about the usual cause of a query without matches, once for each query type
+///
+/// A `Single` panicking on the first frame, before anything was spawned, is a common surprise. The hint
+/// is only given in debug builds, once for each type through a [`Once`](std::sync::Once). Generic statics
+/// do not exist, so those are created on first use and kept by [`TypeId`](core::any::TypeId).
+fn hint_no_entities<D: QueryData + 'static, F: QueryFilter + 'static>() {
+    #[cfg(debug_assertions)]
+    {
+        use alloc::{boxed::Box, collections::BTreeMap};
+        use core::any::TypeId;
+        use std::sync::{Mutex, Once, PoisonError};
+
+        static HINTS: Mutex<BTreeMap<TypeId, &'static Once>> = Mutex::new(BTreeMap::new());
+
+        let once: &'static Once = *HINTS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(TypeId::of::<QueryState<D, F>>())
+            .or_insert_with(|| Box::leak(Box::new(Once::new())));
+
+        once.call_once(|| {
+            bevy_utils::tracing::warn!(
+                "No entities fit the query {}\nhint: did you mean to order this system after your spawn system, or use OptionalSingle?",
+                core::any::type_name::<QueryState<D, F>>(),
+            );
+        });
+    }
+}
+
+
+/// State of a variant that reads more world data than its query while resolving
+///
+/// The extra reads are declared as the system param `P`. Its `init_state` registers the access in
+/// [`SystemMeta`] and panics on conflicts exactly like it would as a standalone param, so the extra
+/// data can not alias anything else the system accesses.
+#[doc(hidden)]
+pub struct ExtendedState<D: QueryData, F: QueryFilter, P: SystemParam> {
+    pub(crate) query: QueryState<D, F>,
+    pub(crate) extra: P::State,
+}
+
+impl<D: QueryData + 'static, F: QueryFilter + 'static, P: SystemParam> ExtendedState<D, F, P> {
+    pub(crate) fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
+        Self {
+            query: init_query_state(world, system_meta),
+            extra: P::init_state(world, system_meta),
+        }
+    }
+
+    /// # Safety
+    /// Same as [`SystemParam::new_archetype`]
+    pub(crate) unsafe fn new_archetype(&mut self, archetype: &Archetype, system_meta: &mut SystemMeta) {
+        <Query<D, F> as SystemParam>::new_archetype(&mut self.query, archetype, system_meta);
+        P::new_archetype(&mut self.extra, archetype, system_meta);
+    }
+}
+
+
+/// Resolves the single item of `state`, the only place calling into the single-item API of `bevy_ecs`
+///
+/// Every single resolution of the crate goes through here, including [`WorldSingleExt`] and the
+/// `assert_single_err!` macro. The name and signature of this API changed between Bevy releases
+/// (`get_single_unchecked_manual` here, `single_unchecked_manual` later on), so a Bevy upgrade only
+/// needs a new shim here. Supported Bevy range: 0.14.x, matching the `bevy_ecs` requirement in
+/// `Cargo.toml`. There is no build matrix: the doctests are the tests and need to pass for every
+/// supported version, so widening the range means running them against each version.
+///
+/// # Safety
+/// Same as [`QueryState::get_single_unchecked_manual`]
+pub(crate) unsafe fn query_single<'w, D: QueryData, F: QueryFilter>(
+    state: &QueryState<D, F>,
+    world: UnsafeWorldCell<'w>,
+    last_run: Tick,
+    this_run: Tick,
+) -> Result<D::Item<'w>, QuerySingleError> {
+    state.get_single_unchecked_manual(world, last_run, this_run)
+}
+
+
+/// Iterates the items of `state` for the lifetime of the world, like [`query_single`] does for one item
+///
+/// # Safety
+/// Same as [`QueryState::iter_unchecked_manual`]
+pub(crate) unsafe fn query_iter<'w, 's, D: QueryData, F: QueryFilter>(
+    state: &'s QueryState<D, F>,
+    world: UnsafeWorldCell<'w>,
+    last_run: Tick,
+    this_run: Tick,
+) -> QueryIter<'w, 's, D, F> {
+    state.iter_unchecked_manual(world, last_run, this_run)
+}
+
+
+/// Fetches the item of `entity` from `state`, with the same change ticks [`resolve_single`] would use
+///
+/// # Safety
+/// Same as [`SystemParam::get_param`] of a [`Query`] using this `state`
+pub(crate) unsafe fn fetch_entity<'w, D: QueryData + 'static, F: QueryFilter + 'static>(
+    state: &mut QueryState<D, F>,
+    system_meta: &SystemMeta,
+    world: UnsafeWorldCell<'w>,
+    change_tick: Tick,
+    entity: Entity,
+) -> Result<D::Item<'w>, QueryEntityError> {
+    state.validate_world(world.id());
+
+    state.get_unchecked_manual(world, entity, system_last_run(system_meta, world, change_tick), change_tick)
+}
+
+
+/// Reads the `last_run` tick of the system from [`SystemMeta`]
+///
+/// `bevy_ecs` keeps the field private, but hands it out through [`SystemChangeTick`], so this goes
+/// through that param instead of relying on the layout of [`SystemMeta`].
+pub(crate) fn system_last_run(system_meta: &SystemMeta, world: UnsafeWorldCell, change_tick: Tick) -> Tick {
+    // SAFETY: `SystemChangeTick` only copies ticks and does not access `world`
+    unsafe { <SystemChangeTick as SystemParam>::get_param(&mut (), system_meta, world, change_tick) }.last_run()
 }
@@ -0,0 +1,171 @@
+use core::{any::type_name, ops::{Deref, DerefMut}, time::Duration};
+use std::{collections::HashMap, sync::{Mutex, PoisonError}, time::Instant};
+
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Query, ReadOnlySystemParam, Res, Resource, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{resolve_single, Duplicates, ExtendedState, SingleDescriptor};
+
+
+/// Get single entity from query like [`Single`](crate::Single), recording each resolution in [`SingleMetrics`]
+/// 
+/// Counting the matches walks the whole query, so this is meant for profiling rather than for every
+/// single in a shipped build. The [`SingleMetrics`] resource is inserted when the system is initialized.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::System;
+/// # use bevy_single::prelude::*;
+/// # use bevy_single::SingleMetrics;
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// pub fn player_height(player: MeteredSingle<&Transform, With<Player>>) -> f32 {
+///     player.translation.y
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::default(), Player));
+/// # world.spawn(Transform::default());
+/// # let mut system = IntoSystem::into_system(player_height);
+/// # system.initialize(&mut world);
+/// # system.run((), &mut world);
+/// # system.run((), &mut world);
+/// let metric = world.resource::<SingleMetrics>().get::<&Transform, With<Player>>().unwrap();
+/// assert_eq!(metric.runs, 2);
+/// assert_eq!(metric.matches, 1);
+/// # system.run((), &mut world);
+/// # assert_eq!(world.resource::<SingleMetrics>().get::<&Transform, With<Player>>().unwrap().runs, 3);
+/// ```
+pub struct MeteredSingle<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub <Desc::D as WorldQuery>::Item<'world>);
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for MeteredSingle<'world, 'state, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for MeteredSingle<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for MeteredSingle<'w, 's, D, F>
+{
+}
+
+
+/// Resolution statistics of one single, as recorded by [`MeteredSingle`]
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct SingleMetric {
+    /// Number of resolutions
+    pub runs: u64,
+    /// Time spent resolving, over all runs
+    pub total: Duration,
+    /// Time spent by the latest resolution
+    pub last: Duration,
+    /// Entities matched by the latest resolution
+    pub matches: usize,
+}
+
+impl SingleMetric {
+    /// Mean time spent per resolution
+    pub fn mean(&self) -> Duration {
+        if self.runs == 0 {
+            return Duration::ZERO;
+        }
+
+        self.total.div_f64(self.runs as f64)
+    }
+}
+
+
+/// Resource collecting the [`SingleMetric`] of every [`MeteredSingle`], keyed by query
+/// 
+/// Recording goes through a lock, so systems using [`MeteredSingle`] still run in parallel.
+#[derive(Resource, Default)]
+pub struct SingleMetrics(Mutex<HashMap<&'static str, SingleMetric>>);
+
+impl SingleMetrics {
+    /// Returns the metric of `MeteredSingle<D, F>`, if it was resolved at least once
+    pub fn get<D: QueryData, F: QueryFilter>(&self) -> Option<SingleMetric> {
+        self.lock().get(type_name::<QueryState<D, F>>()).copied()
+    }
+
+    /// Returns the metrics of all resolved singles, keyed by query type name, most expensive first
+    pub fn snapshot(&self) -> Vec<(&'static str, SingleMetric)> {
+        let mut metrics: Vec<_> = self.lock().iter().map(|(name, metric)| (*name, *metric)).collect();
+        metrics.sort_by(|(_, a), (_, b)| b.total.cmp(&a.total));
+        metrics
+    }
+
+    /// Forgets everything recorded so far
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn record(&self, name: &'static str, elapsed: Duration, matches: usize) {
+        let mut metrics = self.lock();
+        let metric = metrics.entry(name).or_default();
+
+        metric.runs += 1;
+        metric.total += elapsed;
+        metric.last = elapsed;
+        metric.matches = matches;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<&'static str, SingleMetric>> {
+        // Nothing panics while the lock is held, so the map is consistent even if poisoned
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta, and the
+// metrics resource is registered as a read through `Res`. If either conflicts with any prior access,
+// a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for MeteredSingle<'ww, 'ss, D, F> {
+    type State = ExtendedState<D, F, Res<'static, SingleMetrics>>;
+    type Item<'w, 's> = MeteredSingle<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        world.init_resource::<SingleMetrics>();
+
+        ExtendedState::init(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        state.new_archetype(archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's and the metrics' world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that they need. The matches are counted before the item is fetched.
+        unsafe {
+            let metrics = <Res<SingleMetrics> as SystemParam>::get_param(&mut state.extra, system_meta, world, change_tick);
+            let matches = <Query<D, F> as SystemParam>::get_param(&mut state.query, system_meta, world, change_tick).iter().count();
+
+            let start = Instant::now();
+            let single = resolve_single(&mut state.query, system_meta, world, change_tick, Duplicates::Panic);
+            metrics.record(type_name::<QueryState<D, F>>(), start.elapsed(), matches);
+
+            MeteredSingle(single)
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use bevy_ecs::{archetype::Archetype, bundle::Bundle, component::Tick, entity::Entity, query::{QueryData, QueryFilter, QueryState, WorldQuery}, system::{Commands, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World}};
+
+use crate::OptionalSingle;
+
+
+/// Single entity from query that is spawned through [`Commands`] when missing
+/// 
+/// Spawning is deferred like any other command, so the new entity is only resolved from the next run of
+/// the system on, after the commands were applied. Until then [`SingleOrSpawn::get_or_spawn`] returns
+/// `None`. Several matches panic like [`Single`](crate::Single).
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::{query::QuerySingleError, system::System};
+/// # use bevy_single::{assert_single_err, prelude::*};
+/// #[derive(Component)]
+/// struct Score(u32);
+/// 
+/// pub fn add_point(mut score: SingleOrSpawn<&mut Score>) -> Option<u32> {
+///     let score = score.get_or_spawn(Score(0))?;
+///     score.0 += 1;
+///     Some(score.0)
+/// }
+/// # let mut world = World::new();
+/// # let mut system = IntoSystem::into_system(add_point);
+/// # system.initialize(&mut world);
+/// # assert_eq!(system.run((), &mut world), None);
+/// # assert_single_err!(world, &Score, (), QuerySingleError::NoEntities);
+/// # system.apply_deferred(&mut world);
+/// # assert_eq!(system.run((), &mut world), Some(1));
+/// # system.apply_deferred(&mut world);
+/// # assert_eq!(system.run((), &mut world), Some(2));
+/// # assert_eq!(world.query::<&Score>().iter(&world).count(), 1);
+/// ```
+pub struct SingleOrSpawn<'world, 'state, D: QueryData, F: QueryFilter = ()> {
+    single: OptionalSingle<'world, 'state, D, F>,
+    commands: Commands<'world, 'state>,
+    queued: bool,
+}
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter> SingleOrSpawn<'world, 'state, D, F> {
+    /// Returns the item, or queues spawning `bundle` and returns `None` if nothing matched
+    ///
+    /// The spawn is queued at most once per system run. The bundle has to make the spawned entity match
+    /// `D` and `F`, otherwise every run spawns another entity. Debug builds panic when the spawn is
+    /// applied if it does not.
+    pub fn get_or_spawn(&mut self, bundle: impl Bundle) -> Option<&mut <D as WorldQuery>::Item<'world>>
+    where
+        D: 'static,
+        F: 'static,
+    {
+        if self.single.is_none() && !self.queued {
+            self.commands.spawn(bundle).add(|entity: Entity, world: &mut World| {
+                debug_assert!(
+                    world.query_filtered::<D, F>().get(world, entity).is_ok(),
+                    "Entity {entity:?} spawned for {} does not match it",
+                    core::any::type_name::<QueryState<D, F>>(),
+                );
+            });
+
+            self.queued = true;
+        }
+
+        self.single.0.as_mut()
+    }
+}
+
+
+type Inner<D, F> = (OptionalSingle<'static, 'static, D, F>, Commands<'static, 'static>);
+
+// SAFETY: All access is registered by the inner `OptionalSingle` and `Commands`, which are
+// forwarded to as a tuple param.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for SingleOrSpawn<'ww, 'ss, D, F> {
+    type State = <Inner<D, F> as SystemParam>::State;
+    type Item<'w, 's> = SingleOrSpawn<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        <Inner<D, F> as SystemParam>::init_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Inner<D, F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    fn apply(state: &mut Self::State, system_meta: &SystemMeta, world: &mut World) {
+        <Inner<D, F> as SystemParam>::apply(state, system_meta, world)
+    }
+
+    fn queue(state: &mut Self::State, system_meta: &SystemMeta, world: DeferredWorld) {
+        <Inner<D, F> as SystemParam>::queue(state, system_meta, world)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: Forwarded from the caller, the inner params registered their access in `init_state`
+        let (single, commands) = unsafe {
+            <Inner<D, F> as SystemParam>::get_param(state, system_meta, world, change_tick)
+        };
+
+        SingleOrSpawn { single, commands, queued: false }
+    }
+}
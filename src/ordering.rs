@@ -0,0 +1,177 @@
+use alloc::boxed::Box;
+use core::{any::{type_name, TypeId}, fmt::{self, Debug}, hash::{Hash, Hasher}, marker::PhantomData};
+
+use bevy_ecs::{label::DynEq, query::QueryFilter, schedule::{apply_deferred, IntoSystemConfigs, IntoSystemSetConfigs, ScheduleLabel, Schedules, SystemSet}};
+
+
+/// System set for systems spawning the single entity matching the filter `F`
+/// 
+/// See [`OrderSingleExt::order_single`].
+pub struct SpawnsSingle<F: QueryFilter + 'static>(PhantomData<fn() -> F>);
+
+/// System set for systems resolving a single entity matching the filter `F`
+/// 
+/// See [`OrderSingleExt::order_single`].
+pub struct ConsumesSingle<F: QueryFilter + 'static>(PhantomData<fn() -> F>);
+
+macro_rules! impl_single_set {
+    ($set:ident) => {
+        impl<F: QueryFilter + 'static> Default for $set<F> {
+            fn default() -> Self {
+                Self(PhantomData)
+            }
+        }
+
+        impl<F: QueryFilter + 'static> Clone for $set<F> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<F: QueryFilter + 'static> Copy for $set<F> {}
+
+        impl<F: QueryFilter + 'static> PartialEq for $set<F> {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        impl<F: QueryFilter + 'static> Eq for $set<F> {}
+
+        impl<F: QueryFilter + 'static> Hash for $set<F> {
+            fn hash<H: Hasher>(&self, _state: &mut H) {}
+        }
+
+        impl<F: QueryFilter + 'static> Debug for $set<F> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}<{}>", stringify!($set), type_name::<F>())
+            }
+        }
+
+        // Implemented by hand, as the derive expands to `std` paths
+        impl<F: QueryFilter + 'static> SystemSet for $set<F> {
+            fn dyn_clone(&self) -> Box<dyn SystemSet> {
+                Box::new(*self)
+            }
+
+            fn as_dyn_eq(&self) -> &dyn DynEq {
+                self
+            }
+
+            fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+                TypeId::of::<Self>().hash(&mut state);
+            }
+        }
+    };
+}
+
+impl_single_set!(SpawnsSingle);
+impl_single_set!(ConsumesSingle);
+
+
+/// Orders systems resolving a single entity after the systems spawning it
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::schedule::Schedules;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// #[derive(Resource, Default)]
+/// struct PlayerHeight(f32);
+/// 
+/// fn spawn_player(mut commands: Commands) {
+///     commands.spawn((Transform::from_xyz(0.0, 4.0, 0.0), Player));
+/// }
+/// 
+/// fn record_height(player: Single<&Transform, With<Player>>, mut height: ResMut<PlayerHeight>) {
+///     height.0 = player.translation.y;
+/// }
+/// 
+/// let mut schedules = Schedules::default();
+/// schedules
+///     .add_systems(Update, (
+///         record_height.in_set(ConsumesSingle::<With<Player>>::default()),
+///         spawn_player.in_set(SpawnsSingle::<With<Player>>::default()),
+///     ))
+///     .order_single::<With<Player>>(Update);
+/// 
+/// let mut world = World::new();
+/// world.init_resource::<PlayerHeight>();
+/// world.insert_resource(schedules);
+/// world.run_schedule(Update);
+/// assert_eq!(world.resource::<PlayerHeight>().0, 4.0);
+/// ```
+pub trait OrderSingleExt {
+    /// Runs [`ConsumesSingle<F>`] after [`SpawnsSingle<F>`] in `schedule`
+    fn order_single<F: QueryFilter + 'static>(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+
+    /// Same as [`OrderSingleExt::order_single`], with an explicit [`apply_deferred`] between the two sets
+    /// 
+    /// Singles spawned through `Commands` in [`SpawnsSingle<F>`] are then visible in [`ConsumesSingle<F>`]
+    /// even if the schedule does not insert sync points on its own.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::schedule::{ScheduleBuildSettings, Schedules};
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(Resource, Default)]
+    /// # struct PlayerHeight(f32);
+    /// # fn spawn_player(mut commands: Commands) {
+    /// #     commands.spawn((Transform::from_xyz(0.0, 4.0, 0.0), Player));
+    /// # }
+    /// # fn record_height(player: Single<&Transform, With<Player>>, mut height: ResMut<PlayerHeight>) {
+    /// #     height.0 = player.translation.y;
+    /// # }
+    /// let mut update = Schedule::new(Update);
+    /// update.set_build_settings(ScheduleBuildSettings { auto_insert_apply_deferred: false, ..default() });
+    /// 
+    /// let mut schedules = Schedules::default();
+    /// schedules.insert(update);
+    /// schedules
+    ///     .add_systems(Update, (
+    ///         record_height.in_set(ConsumesSingle::<With<Player>>::default()),
+    ///         spawn_player.in_set(SpawnsSingle::<With<Player>>::default()),
+    ///     ))
+    ///     .sync_single::<With<Player>>(Update);
+    /// 
+    /// let mut world = World::new();
+    /// world.init_resource::<PlayerHeight>();
+    /// world.insert_resource(schedules);
+    /// world.run_schedule(Update);
+    /// assert_eq!(world.resource::<PlayerHeight>().0, 4.0);
+    /// ```
+    fn sync_single<F: QueryFilter + 'static>(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+}
+
+impl OrderSingleExt for Schedules {
+    fn order_single<F: QueryFilter + 'static>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.configure_sets(schedule, ConsumesSingle::<F>::default().after(SpawnsSingle::<F>::default()))
+    }
+
+    fn sync_single<F: QueryFilter + 'static>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        let schedule = schedule.intern();
+
+        self.order_single::<F>(schedule)
+            .add_systems(schedule, apply_deferred.after(SpawnsSingle::<F>::default()).before(ConsumesSingle::<F>::default()))
+    }
+}
+
+#[cfg(feature = "app")]
+impl OrderSingleExt for bevy_app::App {
+    fn order_single<F: QueryFilter + 'static>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.configure_sets(schedule, ConsumesSingle::<F>::default().after(SpawnsSingle::<F>::default()))
+    }
+
+    fn sync_single<F: QueryFilter + 'static>(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        let schedule = schedule.intern();
+
+        self.order_single::<F>(schedule)
+            .add_systems(schedule, apply_deferred.after(SpawnsSingle::<F>::default()).before(ConsumesSingle::<F>::default()))
+    }
+}
@@ -0,0 +1,54 @@
+use bevy_ecs::change_detection::{Mut, Ref};
+
+
+/// Query item that can be cloned into an owned value, see [`Single::to_owned_tuple`](crate::Single::to_owned_tuple)
+pub trait ToOwnedItem {
+    /// Owned value of the item, the component itself for references and tuples of those for tuples
+    type Owned;
+
+    /// Clones the components the item points to, without marking them as changed
+    fn to_owned_item(&self) -> Self::Owned;
+}
+
+impl<T: Clone> ToOwnedItem for &T {
+    type Owned = T;
+
+    fn to_owned_item(&self) -> T {
+        (*self).clone()
+    }
+}
+
+impl<T: Clone> ToOwnedItem for Ref<'_, T> {
+    type Owned = T;
+
+    fn to_owned_item(&self) -> T {
+        (**self).clone()
+    }
+}
+
+impl<T: Clone> ToOwnedItem for Mut<'_, T> {
+    type Owned = T;
+
+    fn to_owned_item(&self) -> T {
+        (**self).clone()
+    }
+}
+
+macro_rules! impl_to_owned_item_tuple {
+    ($($item: ident),*) => {
+        impl<$($item: ToOwnedItem),*> ToOwnedItem for ($($item,)*) {
+            type Owned = ($($item::Owned,)*);
+
+            #[allow(non_snake_case)]
+            fn to_owned_item(&self) -> Self::Owned {
+                let ($($item,)*) = self;
+                ($($item.to_owned_item(),)*)
+            }
+        }
+    };
+}
+
+impl_to_owned_item_tuple!(A);
+impl_to_owned_item_tuple!(A, B);
+impl_to_owned_item_tuple!(A, B, C);
+impl_to_owned_item_tuple!(A, B, C, D);
@@ -0,0 +1,65 @@
+use bevy_ecs::{component::Component, query::QueryFilter, system::{Commands, SystemId}};
+
+use crate::Single;
+
+
+/// Creates a system that resolves a single `T` and outputs a clone of it, to be piped into another system
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+/// 
+/// fn is_critical(In(health): In<Health>) -> bool {
+///     health.0 < 20
+/// }
+/// 
+/// # let mut world = World::new();
+/// # world.spawn((Health(10), Player));
+/// # world.spawn(Health(100));
+/// let system = pipe_single::<Health, With<Player>>().pipe(is_critical);
+/// # assert!(world.run_system_once(system));
+/// ```
+pub fn pipe_single<T: Component + Clone, F: QueryFilter + 'static>() -> impl FnMut(Single<&T, F>) -> T + Clone {
+    move |Single(value): Single<&T, F>| value.clone()
+}
+
+/// Creates a system that resolves a single `T` and runs the one-shot `system` with a clone of it as input
+/// 
+/// The one-shot system runs when the commands of the returned system are applied.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+/// 
+/// #[derive(Resource, Default)]
+/// struct LastReported(u32);
+/// 
+/// fn report(In(health): In<Health>, mut last: ResMut<LastReported>) {
+///     last.0 = health.0;
+/// }
+/// 
+/// # let mut world = World::new();
+/// # world.init_resource::<LastReported>();
+/// # world.spawn((Health(10), Player));
+/// # world.spawn(Health(100));
+/// let report = world.register_system(report);
+/// world.run_system_once(run_with_single::<Health, With<Player>>(report));
+/// # assert_eq!(world.resource::<LastReported>().0, 10);
+/// ```
+pub fn run_with_single<T: Component + Clone, F: QueryFilter + 'static>(system: SystemId<T>) -> impl FnMut(Single<&T, F>, Commands) + Clone {
+    move |Single(value): Single<&T, F>, mut commands: Commands| commands.run_system_with_input(system, value.clone())
+}
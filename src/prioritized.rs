@@ -0,0 +1,143 @@
+use core::{any::type_name, cmp::Ordering, ops::{Deref, DerefMut}};
+
+use bevy_ecs::{archetype::Archetype, component::{Component, Tick}, entity::Entity, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{fetch_entity, init_query_state, SingleDescriptor};
+
+
+/// Get single entity from query, chosen among all matches by the greatest priority component `P`
+/// 
+/// Only entities with `P` are considered. Panics if nothing matches, or if several entities share the
+/// greatest priority. `D` may not write `P`.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+/// struct ListenerPriority(i32);
+/// 
+/// pub fn listener_name(listener: PrioritizedSingle<ListenerPriority, &Name>) -> String {
+///     listener.as_str().to_owned()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((ListenerPriority(0), Name::new("Ambient")));
+/// # world.spawn((ListenerPriority(2), Name::new("Player")));
+/// # world.spawn((ListenerPriority(1), Name::new("Cutscene")));
+/// # assert_eq!(world.run_system_once(listener_name), "Player");
+/// ```
+/// 
+/// A tie at the top panics:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+/// # struct ListenerPriority(i32);
+/// # pub fn listener_name(listener: PrioritizedSingle<ListenerPriority, &Name>) -> String {
+/// #     listener.as_str().to_owned()
+/// # }
+/// # let mut world = World::new();
+/// # world.spawn((ListenerPriority(0), Name::new("Ambient")));
+/// world.spawn((ListenerPriority(2), Name::new("Player")));
+/// world.spawn((ListenerPriority(2), Name::new("Cutscene")));
+/// # world.run_system_once(listener_name);
+/// ```
+/// 
+/// So does no match at all:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+/// # struct ListenerPriority(i32);
+/// # pub fn listener_name(listener: PrioritizedSingle<ListenerPriority, &Name>) -> String {
+/// #     listener.as_str().to_owned()
+/// # }
+/// # let mut world = World::new();
+/// world.spawn(Name::new("Ambient"));
+/// # world.run_system_once(listener_name);
+/// ```
+pub struct PrioritizedSingle<'world, 'state, P: Component + Ord, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub <Desc::D as WorldQuery>::Item<'world>);
+
+
+impl<'world, 'state, P: Component + Ord, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for PrioritizedSingle<'world, 'state, P, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, P: Component + Ord, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for PrioritizedSingle<'world, 'state, P, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, P: Component + Ord, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for PrioritizedSingle<'w, 's, P, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, P: Component + Ord, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for PrioritizedSingle<'ww, 'ss, P, D, F> {
+    type State = QueryState<(Entity, &'static P, D), F>;
+    type Item<'w, 's> = PrioritizedSingle<'w, 's, P, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, (Entity, &'static P, D), F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs.
+        unsafe {
+            let entity = {
+                let candidates = <Query<(Entity, &P, D), F> as SystemParam>::get_param(state, system_meta, world, change_tick);
+                let mut top: Option<(Entity, &P)> = None;
+                let mut tied = false;
+
+                for (entity, priority, _) in candidates.iter() {
+                    match top.map(|(_, best)| priority.cmp(best)) {
+                        None | Some(Ordering::Greater) => {
+                            top = Some((entity, priority));
+                            tied = false;
+                        }
+                        Some(Ordering::Equal) => tied = true,
+                        Some(Ordering::Less) => {}
+                    }
+                }
+
+                match top {
+                    Some((entity, _)) if !tied => entity,
+                    Some(_) => panic!("Multiple entities with the greatest {} fit the query {}", type_name::<P>(), type_name::<Self>()),
+                    None => panic!("No entities with {} fit the query {}", type_name::<P>(), type_name::<Self>()),
+                }
+            };
+
+            let (_, _, single) = fetch_entity(state, system_meta, world, change_tick, entity).unwrap();
+
+            PrioritizedSingle(single)
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use bevy_render::Extract;
+
+use crate::Single;
+
+
+/// [`Single`] read from the main world in an extraction system, see [`Extract`]
+/// 
+/// Same as `Extract<Single<D, F>>`. Available with the `render` feature.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy::render::MainWorld;
+/// # use bevy_single::ExtractSingle;
+/// #[derive(Component)]
+/// struct MainCamera;
+/// 
+/// #[derive(Resource)]
+/// struct ExtractedCameraHeight(f32);
+/// 
+/// fn extract_camera_height(mut commands: Commands, camera: ExtractSingle<&Transform, With<MainCamera>>) {
+///     commands.insert_resource(ExtractedCameraHeight(camera.translation.y));
+/// }
+/// 
+/// let mut main_world = MainWorld::default();
+/// main_world.spawn((Transform::from_xyz(0.0, 8.0, 0.0), MainCamera));
+/// 
+/// let mut render_world = World::new();
+/// render_world.insert_resource(main_world);
+/// render_world.run_system_once(extract_camera_height);
+/// assert_eq!(render_world.resource::<ExtractedCameraHeight>().0, 8.0);
+/// ```
+pub type ExtractSingle<'world, 'state, D, F = ()> = Extract<'world, 'state, Single<'static, 'static, D, F>>;
@@ -0,0 +1,152 @@
+use core::{any::type_name, ops::{Deref, DerefMut}};
+
+use bevy_ecs::{archetype::Archetype, component::{Component, Tick}, entity::Entity, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData, With, WorldQuery}, system::{Query, ReadOnlySystemParam, Res, Resource, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{fetch_entity, ExtendedState, SingleDescriptor};
+
+
+/// Resource that picks which of the matched entities a [`SelectedSingle`] resolves to
+pub trait SingleSelector: Resource {
+    /// Component compared against the resource
+    type Key: Component;
+
+    /// Returns `true` if the entity holding `key` is the selected one
+    fn selects(&self, key: &Self::Key) -> bool;
+}
+
+
+/// Get single entity from query, chosen among all matches by the [`SingleSelector`] resource `S`
+/// 
+/// Only entities with `S::Key` are considered. Panics if the selector does not pick exactly one of them.
+/// `D` may not write `S::Key`.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct LevelId(u32);
+/// 
+/// #[derive(Resource)]
+/// struct CurrentLevel(u32);
+/// 
+/// impl SingleSelector for CurrentLevel {
+///     type Key = LevelId;
+/// 
+///     fn selects(&self, key: &LevelId) -> bool {
+///         key.0 == self.0
+///     }
+/// }
+/// 
+/// pub fn level_name(level: SelectedSingle<CurrentLevel, &Name>) -> String {
+///     level.as_str().to_owned()
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((LevelId(1), Name::new("Forest")));
+/// # world.spawn((LevelId(2), Name::new("Cave")));
+/// # world.spawn((LevelId(3), Name::new("Castle")));
+/// # world.insert_resource(CurrentLevel(2));
+/// # assert_eq!(world.run_system_once(level_name), "Cave");
+/// # world.insert_resource(CurrentLevel(3));
+/// # assert_eq!(world.run_system_once(level_name), "Castle");
+/// ```
+/// 
+/// The selector resource is registered as a read, so writing it from the same system panics:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct LevelId(u32);
+/// # #[derive(Resource)]
+/// # struct CurrentLevel(u32);
+/// # impl SingleSelector for CurrentLevel {
+/// #     type Key = LevelId;
+/// #     fn selects(&self, key: &LevelId) -> bool {
+/// #         key.0 == self.0
+/// #     }
+/// # }
+/// pub fn next_level(_level: SelectedSingle<CurrentLevel, &Name>, mut current: ResMut<CurrentLevel>) {
+///     current.0 += 1;
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((LevelId(1), Name::new("Forest")));
+/// # world.insert_resource(CurrentLevel(1));
+/// # world.run_system_once(next_level);
+/// ```
+pub struct SelectedSingle<'world, 'state, S: SingleSelector, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, (F, With<S::Key>)> = ()>(pub <Desc::D as WorldQuery>::Item<'world>);
+
+
+impl<'world, 'state, S: SingleSelector, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, (F, With<S::Key>)>> Deref for SelectedSingle<'world, 'state, S, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, S: SingleSelector, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, (F, With<S::Key>)>> DerefMut for SelectedSingle<'world, 'state, S, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, S: SingleSelector, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for SelectedSingle<'w, 's, S, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta, and the
+// selector resource is registered as a read through `Res`. If either conflicts with any prior access,
+// a panic will occur.
+unsafe impl<'ww, 'ss, S: SingleSelector, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for SelectedSingle<'ww, 'ss, S, D, F> {
+    type State = ExtendedState<(Entity, &'static S::Key, D), F, Res<'static, S>>;
+    type Item<'w, 's> = SelectedSingle<'w, 's, S, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        ExtendedState::init(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        state.new_archetype(archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's and the selector's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that they need.
+        unsafe {
+            let selector = <Res<S> as SystemParam>::get_param(&mut state.extra, system_meta, world, change_tick);
+            let entity = {
+                let candidates = <Query<(Entity, &S::Key, D), F> as SystemParam>::get_param(&mut state.query, system_meta, world, change_tick);
+                let mut selected = candidates
+                    .iter()
+                    .filter(|(_, key, _)| selector.selects(key))
+                    .map(|(entity, _, _)| entity);
+
+                match (selected.next(), selected.next()) {
+                    (Some(entity), None) => entity,
+                    (None, _) => panic!("No entities selected by {} fit the query {}", type_name::<S>(), type_name::<Self>()),
+                    (Some(_), Some(_)) => panic!("Multiple entities selected by {} fit the query {}", type_name::<S>(), type_name::<Self>()),
+                }
+            };
+
+            let (_, _, single) = fetch_entity(&mut state.query, system_meta, world, change_tick, entity).unwrap();
+
+            SelectedSingle(single)
+        }
+    }
+}
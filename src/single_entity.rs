@@ -0,0 +1,348 @@
+use core::ops::{Deref, DerefMut};
+
+use bevy_ecs::{archetype::Archetype, component::{Component, ComponentTicks, Tick}, entity::Entity, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData, WorldQuery}, system::{Commands, EntityCommands, Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, EntityRef, World}};
+
+use crate::{init_query_state, resolve_single, Duplicates, SingleDescriptor};
+
+
+/// Get single entity from query together with its [`Entity`]
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn despawn_fallen_player(
+///     mut commands: Commands,
+///     SingleEntity(player, transform): SingleEntity<&Transform, With<Player>>,
+/// ) {
+///     if transform.translation.y < -100.0 {
+///         commands.entity(player).despawn();
+///     }
+/// }
+/// ```
+/// 
+/// ## Logging
+/// 
+/// With the `debug-log` feature every resolution logs the resolved entity at `debug` level, to find out
+/// which of several similar entities a system picked up. Without it nothing is logged:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy::log::tracing_subscriber::{layer::{Context, SubscriberExt}, Layer, Registry};
+/// # use bevy::utils::tracing::{subscriber::with_default, Event, Subscriber};
+/// # use bevy_single::prelude::*;
+/// # use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+/// # #[derive(Component)]
+/// # struct Player;
+/// struct CountEvents(Arc<AtomicUsize>);
+/// 
+/// impl<S: Subscriber> Layer<S> for CountEvents {
+///     fn on_event(&self, _event: &Event<'_>, _ctx: Context<'_, S>) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+/// 
+/// pub fn find_player(_player: SingleEntity<(), With<Player>>) {}
+/// 
+/// let events = Arc::new(AtomicUsize::new(0));
+/// let mut world = World::new();
+/// world.spawn(Player);
+/// 
+/// with_default(Registry::default().with(CountEvents(events.clone())), || {
+///     world.run_system_once(find_player);
+/// });
+/// 
+/// assert_eq!(events.load(Ordering::Relaxed) > 0, cfg!(feature = "debug-log"));
+/// ```
+pub struct SingleEntity<'world, 'state, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub Entity, pub <Desc::D as WorldQuery>::Item<'world>);
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> SingleEntity<'world, 'state, D, F, Desc> {
+    /// Returns the resolved entity, captured when the single was resolved
+    /// 
+    /// [`Entity`] implements `Hash` and `Eq`, so it can key caches of per-singleton data:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::System;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// pub fn count_player_frames(player: SingleEntity<(), With<Player>>, mut frames: Local<HashMap<Entity, u32>>) -> u32 {
+    ///     let count = frames.entry(player.entity()).or_default();
+    ///     *count += 1;
+    ///     *count
+    /// }
+    /// # let mut world = World::new();
+    /// # let first = world.spawn(Player).id();
+    /// # let mut system = IntoSystem::into_system(count_player_frames);
+    /// # system.initialize(&mut world);
+    /// # assert_eq!(system.run((), &mut world), 1);
+    /// # assert_eq!(system.run((), &mut world), 2);
+    /// # world.despawn(first);
+    /// # world.spawn(Player);
+    /// # assert_eq!(system.run((), &mut world), 1);
+    /// ```
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+
+    /// Returns the resolved entity together with a reference to its item, without consuming the single
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// 
+    /// pub fn despawn_dead_player(mut commands: Commands, player: SingleEntity<&Health, With<Player>>) {
+    ///     let (entity, health) = player.with_entity();
+    ///     if health.0 == 0 {
+    ///         commands.entity(entity).despawn();
+    ///     }
+    /// }
+    /// # let mut world = World::new();
+    /// # let player = world.spawn((Health(0), Player)).id();
+    /// # world.run_system_once(despawn_dead_player);
+    /// # assert!(world.get_entity(player).is_none());
+    /// ```
+    pub fn with_entity(&self) -> (Entity, &<Desc::D as WorldQuery>::Item<'world>) {
+        (self.0, &self.1)
+    }
+
+    /// Consumes the single, returning the resolved entity together with its item
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// 
+    /// fn damage(mut health: Mut<Health>, amount: u32) -> bool {
+    ///     health.0 = health.0.saturating_sub(amount);
+    ///     health.0 == 0
+    /// }
+    /// 
+    /// pub fn fall_damage(mut commands: Commands, player: SingleEntity<&mut Health, With<Player>>) {
+    ///     let (entity, health) = player.into_parts();
+    ///     if damage(health, 20) {
+    ///         commands.entity(entity).despawn();
+    ///     }
+    /// }
+    /// # let mut world = World::new();
+    /// # let player = world.spawn((Health(30), Player)).id();
+    /// # world.run_system_once(fall_damage);
+    /// # assert_eq!(world.get::<Health>(player).unwrap().0, 10);
+    /// # world.run_system_once(fall_damage);
+    /// # assert!(world.get_entity(player).is_none());
+    /// ```
+    pub fn into_parts(self) -> (Entity, <Desc::D as WorldQuery>::Item<'world>) {
+        (self.0, self.1)
+    }
+
+    /// Returns the [`EntityCommands`] of the resolved entity
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Stunned;
+    /// 
+    /// pub fn stun_player(mut commands: Commands, player: SingleEntity<(), With<Player>>) {
+    ///     player.commands(&mut commands).insert(Stunned);
+    /// }
+    /// # let mut world = World::new();
+    /// # let player = world.spawn(Player).id();
+    /// # world.run_system_once(stun_player);
+    /// # assert!(world.entity(player).contains::<Stunned>());
+    /// ```
+    pub fn commands<'a>(&self, commands: &'a mut Commands) -> EntityCommands<'a> {
+        commands.entity(self.0)
+    }
+
+    /// Returns an [`EntityRef`] of the resolved entity, to read components that are not part of the query data
+    /// 
+    /// This needs a `&World` system param next to the single, which conflicts with any mutable access in
+    /// the same system, so `D` has to be read-only there. `world` has to be the world the single was resolved from.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// 
+    /// pub fn player_health(player: SingleEntity<(), With<Player>>, world: &World) -> u32 {
+    ///     player.entity_ref(world).get::<Health>().map_or(0, |health| health.0)
+    /// }
+    /// # let mut world = World::new();
+    /// # world.spawn((Health(7), Player));
+    /// # world.spawn(Health(3));
+    /// # assert_eq!(world.run_system_once(player_health), 7);
+    /// ```
+    pub fn entity_ref<'a>(&self, world: &'a World) -> EntityRef<'a> {
+        world.entity(self.0)
+    }
+
+    /// Returns `true` if `entity` is the entity this single was resolved to
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(Event)]
+    /// # struct Hit(Entity);
+    /// pub fn count_player_hits(
+    ///     player: SingleEntity<(), With<Player>>,
+    ///     mut hits: EventReader<Hit>,
+    /// ) -> usize {
+    ///     hits.read().filter(|Hit(entity)| player.matches(*entity)).count()
+    /// }
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Events<Hit>>();
+    /// # let player = world.spawn(Player).id();
+    /// # let enemy = world.spawn_empty().id();
+    /// # world.send_event(Hit(player));
+    /// # world.send_event(Hit(enemy));
+    /// # world.send_event(Hit(player));
+    /// # assert_eq!(world.run_system_once(count_player_hits), 2);
+    /// ```
+    pub fn matches(&self, entity: Entity) -> bool {
+        self.0 == entity
+    }
+
+    /// Returns `true` if both singles resolved to the same entity, without comparing their items
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Selected;
+    /// 
+    /// pub fn player_selected(
+    ///     player: SingleEntity<&Transform, With<Player>>,
+    ///     selected: SingleEntity<(), With<Selected>>,
+    /// ) -> bool {
+    ///     player.entity_eq(&selected)
+    /// }
+    /// # let mut world = World::new();
+    /// # let player = world.spawn((Transform::default(), Player, Selected)).id();
+    /// # assert!(world.run_system_once(player_selected));
+    /// # world.entity_mut(player).remove::<Selected>();
+    /// # world.spawn(Selected);
+    /// # assert!(!world.run_system_once(player_selected));
+    /// ```
+    pub fn entity_eq<'w2, 's2, D2: QueryData, F2: QueryFilter, Desc2: SingleDescriptor<'w2, 's2, D2, F2>>(&self, other: &SingleEntity<'w2, 's2, D2, F2, Desc2>) -> bool {
+        self.0 == other.0
+    }
+
+    /// Returns the change ticks of the singleton's `T` component, or `None` if it has no such component
+    /// 
+    /// The ticks are read from `world`, so this is usable from systems that also take `&World`, from
+    /// `SystemState` and from exclusive contexts. A `&World` param conflicts with mutable query data,
+    /// so in systems this pairs with read-only singles.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::component::ComponentTicks;
+    /// # use bevy::ecs::system::RunSystemOnce;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct Config(u32);
+    /// 
+    /// pub fn config_ticks(config: SingleEntity<&Config>, world: &World) -> ComponentTicks {
+    ///     config.get_change_ticks::<Config>(world).unwrap()
+    /// }
+    /// 
+    /// let mut world = World::new();
+    /// world.spawn(Config(1));
+    /// let before = world.run_system_once(config_ticks);
+    /// 
+    /// world.query::<&mut Config>().single_mut(&mut world).0 = 2;
+    /// let after = world.run_system_once(config_ticks);
+    /// 
+    /// assert_eq!(after.added_tick(), before.added_tick());
+    /// assert_ne!(after.last_changed_tick(), before.last_changed_tick());
+    /// ```
+    pub fn get_change_ticks<T: Component>(&self, world: &World) -> Option<ComponentTicks> {
+        world.entity(self.0).get_change_ticks::<T>()
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for SingleEntity<'world, 'state, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+impl<'world, 'state, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for SingleEntity<'world, 'state, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.1
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for SingleEntity<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for SingleEntity<'ww, 'ss, D, F> {
+    type State = QueryState<(Entity, D), F>;
+    type Item<'w, 's> = SingleEntity<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, (Entity, D), F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that the query needs.
+        unsafe {
+            let (entity, single) = resolve_single(state, system_meta, world, change_tick, Duplicates::Panic);
+
+            #[cfg(feature = "debug-log")]
+            bevy_utils::tracing::debug!("{} resolved to {entity:?}", core::any::type_name::<Self>());
+
+            SingleEntity(entity, single)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, QueryState, ReadOnlyQueryData}, system::{Query, ReadOnlySystemParam, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{init_query_state, query_single, system_last_run};
+
+
+/// Single entity from query, falling back to the whole query when there is not exactly one match
+/// 
+/// Matches are counted once per system run, stopping at the second one. Exactly one match gives
+/// [`SingleOrAll::Single`], like [`Single`](crate::Single) would. No match or several matches give
+/// [`SingleOrAll::All`] with the underlying [`Query`], which has the same access and change ticks.
+/// This never panics on its own.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// #[derive(Component)]
+/// struct Target;
+/// 
+/// pub fn aim_height(targets: SingleOrAll<&Transform, With<Target>>) -> f32 {
+///     match targets {
+///         SingleOrAll::Single(target) => target.translation.y,
+///         SingleOrAll::All(targets) => {
+///             let count = targets.iter().count().max(1);
+///             targets.iter().map(|target| target.translation.y).sum::<f32>() / count as f32
+///         }
+///     }
+/// }
+/// # let mut world = World::new();
+/// # assert_eq!(world.run_system_once(aim_height), 0.0);
+/// # world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Target));
+/// # assert_eq!(world.run_system_once(aim_height), 2.0);
+/// # world.spawn((Transform::from_xyz(0.0, 4.0, 0.0), Target));
+/// # assert_eq!(world.run_system_once(aim_height), 3.0);
+/// ```
+pub enum SingleOrAll<'world, 'state, D: QueryData, F: QueryFilter = ()> {
+    /// Exactly one entity matched
+    Single(D::Item<'world>),
+    /// No entity or several entities matched
+    All(Query<'world, 'state, D, F>),
+}
+
+
+impl<'world, 'state, D: QueryData, F: QueryFilter> SingleOrAll<'world, 'state, D, F> {
+    /// Returns `true` if exactly one entity matched
+    pub fn is_single(&self) -> bool {
+        matches!(self, Self::Single(_))
+    }
+
+    /// Returns the single item, or `None` if there was not exactly one match
+    pub fn single(self) -> Option<D::Item<'world>> {
+        match self {
+            Self::Single(single) => Some(single),
+            Self::All(_) => None,
+        }
+    }
+}
+
+unsafe impl<'w, 's, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for SingleOrAll<'w, 's, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'ww, 'ss, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for SingleOrAll<'ww, 'ss, D, F> {
+    type State = QueryState<D, F>;
+    type Item<'w, 's> = SingleOrAll<'w, 's, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        init_query_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'ww, 'ss, D, F> as SystemParam>::new_archetype(state, archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+
+        // SAFETY: Forwarded from the caller, the query's access was registered in `init_state`. The single
+        // item is only handed out when no `Query` is created, so nothing else can fetch it again.
+        unsafe {
+            match query_single(state, world, system_last_run(system_meta, world, change_tick), change_tick) {
+                Ok(single) => SingleOrAll::Single(single),
+                Err(_) => SingleOrAll::All(<Query<D, F> as SystemParam>::get_param(state, system_meta, world, change_tick)),
+            }
+        }
+    }
+}
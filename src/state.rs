@@ -0,0 +1,180 @@
+use core::{marker::PhantomData, ops::{Deref, DerefMut}};
+
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, QuerySingleError, ReadOnlyQueryData, WorldQuery}, system::{ReadOnlySystemParam, Res, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+use bevy_state::state::{State, States};
+
+use crate::{query_single, resolve_single, system_last_run, Duplicates, ExtendedState, SingleDescriptor};
+
+
+/// Single entity from query that has to exist while the state `S` exists
+/// 
+/// The state counts as active while its [`State<S>`] resource exists, which is how computed states and
+/// sub-states model being entered and exited. While active this resolves like [`Single`](crate::Single)
+/// and panics if nothing matches. Otherwise it is `None` when nothing matches, like
+/// [`OptionalSingle`](crate::OptionalSingle). Several matches always panic.
+/// 
+/// A state scoped singleton is usually spawned in `OnEnter(S)` and carries `StateScoped(S)` to be
+/// despawned on `OnExit(S)`. Reading `State<S>` is registered as an access of the system.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::state::app::StatesPlugin;
+/// # use bevy_single::StateScopedSingle;
+/// #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// enum AppState {
+///     #[default]
+///     Menu,
+///     InGame,
+/// }
+/// 
+/// #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// struct InGame;
+/// 
+/// impl ComputedStates for InGame {
+///     type SourceStates = AppState;
+/// 
+///     fn compute(sources: AppState) -> Option<Self> {
+///         (sources == AppState::InGame).then_some(InGame)
+///     }
+/// }
+/// 
+/// #[derive(Component)]
+/// struct Player;
+/// 
+/// #[derive(Resource, Default)]
+/// struct PlayerHeight(Option<f32>);
+/// 
+/// pub fn track_player(player: StateScopedSingle<InGame, &Transform, With<Player>>, mut height: ResMut<PlayerHeight>) {
+///     height.0 = player.into_inner().map(|player| player.translation.y);
+/// }
+/// # let mut app = App::new();
+/// # app.add_plugins(StatesPlugin)
+/// #     .init_state::<AppState>()
+/// #     .add_computed_state::<InGame>()
+/// #     .enable_state_scoped_entities::<InGame>()
+/// #     .init_resource::<PlayerHeight>()
+/// #     .add_systems(OnEnter(InGame), |mut commands: Commands| {
+/// #         commands.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player, StateScoped(InGame)));
+/// #     })
+/// #     .add_systems(Update, track_player);
+/// # app.update();
+/// # assert_eq!(app.world().resource::<PlayerHeight>().0, None);
+/// # app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::InGame);
+/// # app.update();
+/// # app.update();
+/// # assert_eq!(app.world().resource::<PlayerHeight>().0, Some(2.0));
+/// # app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::Menu);
+/// # app.update();
+/// # assert_eq!(app.world().resource::<PlayerHeight>().0, None);
+/// ```
+/// 
+/// Missing the singleton while the state is active panics:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::state::app::StatesPlugin;
+/// # use bevy_single::StateScopedSingle;
+/// # #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// # enum AppState {
+/// #     #[default]
+/// #     Menu,
+/// #     InGame,
+/// # }
+/// # #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// # struct InGame;
+/// # impl ComputedStates for InGame {
+/// #     type SourceStates = AppState;
+/// #     fn compute(sources: AppState) -> Option<Self> {
+/// #         (sources == AppState::InGame).then_some(InGame)
+/// #     }
+/// # }
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn player_height(player: StateScopedSingle<InGame, &Transform, With<Player>>) -> Option<f32> {
+///     player.into_inner().map(|player| player.translation.y)
+/// }
+/// # let mut app = App::new();
+/// # app.add_plugins(StatesPlugin)
+/// #     .init_state::<AppState>()
+/// #     .add_computed_state::<InGame>()
+/// #     .add_systems(Update, player_height);
+/// # app.update();
+/// # app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::InGame);
+/// # app.update();
+/// ```
+pub struct StateScopedSingle<'world, 'state, S: States, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub Option<<Desc::D as WorldQuery>::Item<'world>>, PhantomData<fn() -> S>);
+
+
+impl<'world, 'state, S: States, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for StateScopedSingle<'world, 'state, S, D, F, Desc> {
+    type Target = Option<<Desc::D as WorldQuery>::Item<'world>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, S: States, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for StateScopedSingle<'world, 'state, S, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'world, 'state, S: States, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> StateScopedSingle<'world, 'state, S, D, F, Desc> {
+    /// Returns the item, or `None` if the state is not active and nothing matched
+    pub fn into_inner(self) -> Option<<Desc::D as WorldQuery>::Item<'world>> {
+        self.0
+    }
+}
+
+unsafe impl<'w, 's, S: States, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for StateScopedSingle<'w, 's, S, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta, and the
+// state resource is registered as a read through `Option<Res>`. If either conflicts with any prior
+// access, a panic will occur.
+unsafe impl<'ww, 'ss, S: States, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for StateScopedSingle<'ww, 'ss, S, D, F> {
+    type State = ExtendedState<D, F, Option<Res<'static, State<S>>>>;
+    type Item<'w, 's> = StateScopedSingle<'w, 's, S, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        ExtendedState::init(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        state.new_archetype(archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.query.validate_world(world.id());
+
+        // SAFETY: We have registered all of the query's and the state's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that they need. This is the only item handed out for this run.
+        let single = unsafe {
+            let active = <Option<Res<State<S>>> as SystemParam>::get_param(&mut state.extra, system_meta, world, change_tick).is_some();
+
+            match query_single(&state.query, world, system_last_run(system_meta, world, change_tick), change_tick) {
+                Ok(single) => Some(single),
+                Err(QuerySingleError::NoEntities(_)) if !active => None,
+                // Same messages as `Single`
+                Err(_) => Some(resolve_single(&mut state.query, system_meta, world, change_tick, Duplicates::Panic)),
+            }
+        };
+
+        StateScopedSingle(single, PhantomData)
+    }
+}
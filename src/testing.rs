@@ -0,0 +1,38 @@
+/// Asserts that resolving a single with query data `D` and filter `F` in `world` fails with the given
+/// [`QuerySingleError`](bevy_ecs::query::QuerySingleError) variant
+/// 
+/// Available with the `test-utils` feature.
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::query::QuerySingleError;
+/// # use bevy_single::assert_single_err;
+/// # #[derive(Component)]
+/// # struct Player;
+/// let mut world = World::new();
+/// assert_single_err!(world, &Transform, With<Player>, QuerySingleError::NoEntities);
+/// 
+/// world.spawn((Transform::default(), Player));
+/// world.spawn((Transform::default(), Player));
+/// assert_single_err!(world, &Transform, With<Player>, QuerySingleError::MultipleEntities);
+/// ```
+#[macro_export]
+macro_rules! assert_single_err {
+    ($world:expr, $data:ty, $filter:ty, $error:path) => {
+        match $crate::WorldSingleExt::get_single::<$data, $filter>(&mut $world) {
+            Err($crate::SingleError::Query($error(_))) => {}
+            Err(error) => panic!(
+                "expected {} resolving Single<{}, {}>, got: {error}",
+                stringify!($error),
+                stringify!($data),
+                stringify!($filter),
+            ),
+            Ok(_) => panic!(
+                "expected {} resolving Single<{}, {}>, but it resolved",
+                stringify!($error),
+                stringify!($data),
+                stringify!($filter),
+            ),
+        }
+    };
+}
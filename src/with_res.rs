@@ -0,0 +1,120 @@
+use core::ops::{Deref, DerefMut};
+
+use bevy_ecs::{archetype::Archetype, component::Tick, query::{QueryData, QueryFilter, ReadOnlyQueryData, WorldQuery}, system::{ReadOnlySystemParam, Res, Resource, SystemMeta, SystemParam}, world::{unsafe_world_cell::UnsafeWorldCell, World}};
+
+use crate::{resolve_single, Duplicates, ExtendedState, SingleDescriptor};
+
+
+/// Single entity from query together with the resource `R`, resolved as one param
+/// 
+/// Query data can only access components, so a resource can not be part of `D`:
+/// ```rust,compile_fail
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Gravity(f32);
+/// pub fn fall(player: Single<(&mut Transform, Res<Gravity>)>) {}
+/// ```
+/// 
+/// This param reads the resource next to the single instead. Its `init_state` registers the read of `R`
+/// through [`Res`] in addition to the query access, so it panics on conflicting access, such as a
+/// `ResMut<R>` in the same system, exactly like a separate `Res<R>` param would. Panics like
+/// [`Single`](crate::Single) if there is not exactly one match, or like `Res` if `R` does not exist.
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// #[derive(Resource)]
+/// struct Gravity(f32);
+/// 
+/// pub fn fall(mut player: SingleWithRes<Gravity, &mut Transform, With<Player>>) {
+///     let gravity = player.1.0;
+///     player.translation.y -= gravity;
+/// }
+/// # let mut world = World::new();
+/// # world.insert_resource(Gravity(2.0));
+/// # let player = world.spawn((Transform::from_xyz(0.0, 10.0, 0.0), Player)).id();
+/// # world.run_system_once(fall);
+/// # assert_eq!(world.get::<Transform>(player).unwrap().translation.y, 8.0);
+/// ```
+/// 
+/// Writing the resource from the same system panics:
+/// ```rust,should_panic
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Resource)]
+/// # struct Gravity(f32);
+/// pub fn fall(_player: SingleWithRes<Gravity, &mut Transform, With<Player>>, _gravity: ResMut<Gravity>) {}
+/// # let mut world = World::new();
+/// # world.insert_resource(Gravity(2.0));
+/// # world.spawn((Transform::default(), Player));
+/// # world.run_system_once(fall);
+/// ```
+pub struct SingleWithRes<'world, 'state, R: Resource, D: QueryData, F: QueryFilter = (), Desc: SingleDescriptor<'world, 'state, D, F> = ()>(pub <Desc::D as WorldQuery>::Item<'world>, pub Res<'world, R>);
+
+
+impl<'world, 'state, R: Resource, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> Deref for SingleWithRes<'world, 'state, R, D, F, Desc> {
+    type Target = <Desc::D as WorldQuery>::Item<'world>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'world, 'state, R: Resource, D: QueryData, F: QueryFilter, Desc: SingleDescriptor<'world, 'state, D, F>> DerefMut for SingleWithRes<'world, 'state, R, D, F, Desc> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+unsafe impl<'w, 's, R: Resource, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> ReadOnlySystemParam
+    for SingleWithRes<'w, 's, R, D, F>
+{
+}
+
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta, and the
+// resource is registered as a read through `Res`. If either conflicts with any prior access, a panic
+// will occur.
+unsafe impl<'ww, 'ss, R: Resource, D: QueryData + 'static, F: QueryFilter + 'static> SystemParam for SingleWithRes<'ww, 'ss, R, D, F> {
+    type State = ExtendedState<D, F, Res<'static, R>>;
+    type Item<'w, 's> = SingleWithRes<'w, 's, R, D, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        ExtendedState::init(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        state.new_archetype(archetype, system_meta)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: We have registered all of the query's and the resource's world accesses,
+        // so the caller ensures that `world` has permission to access any
+        // world data that they need.
+        unsafe {
+            let resource = <Res<R> as SystemParam>::get_param(&mut state.extra, system_meta, world, change_tick);
+            let single = resolve_single(&mut state.query, system_meta, world, change_tick, Duplicates::Panic);
+
+            SingleWithRes(single, resource)
+        }
+    }
+}
@@ -0,0 +1,486 @@
+use bevy_ecs::{bundle::Bundle, change_detection::DetectChangesMut, component::Component, entity::Entity, query::{QueryData, QueryFilter, QuerySingleError, QueryState, ROQueryItem}, system::Resource, world::{World, WorldId}};
+
+use crate::{query_single, SingleError};
+
+
+/// Cached [`QueryState`] used by [`WorldSingleExt::single_cached`], one resource per query
+/// 
+/// It can also be kept around on its own, e.g. by tools resolving the same single repeatedly.
+#[derive(Resource)]
+pub struct CachedSingleState<D: QueryData + 'static, F: QueryFilter + 'static = ()>(pub QueryState<D, F>, WorldId);
+
+
+impl<D: QueryData + 'static, F: QueryFilter + 'static> CachedSingleState<D, F> {
+    /// Creates the state for `world`
+    pub fn new(world: &mut World) -> Self {
+        Self(world.query_filtered(), world.id())
+    }
+
+    /// Registers archetypes created since the last refresh, so [`CachedSingleState::get`] sees their entities
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::CachedSingleState;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// let mut world = World::new();
+    /// let mut state = CachedSingleState::<&Transform, With<Player>>::new(&mut world);
+    /// world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player));
+    /// assert!(state.get(&world).is_err());
+    /// 
+    /// state.refresh(&world);
+    /// assert_eq!(state.get(&world).unwrap().translation.y, 1.0);
+    /// ```
+    pub fn refresh(&mut self, world: &World) {
+        self.0.update_archetypes(world);
+    }
+
+    /// Resolves the single read-only item, only considering archetypes known at the last refresh
+    /// 
+    /// Fails with [`SingleError::WorldMismatch`] if `world` is not the one the state was created from.
+    pub fn get<'w>(&self, world: &'w World) -> Result<ROQueryItem<'w, D>, SingleError> {
+        if self.1 != world.id() {
+            return Err(SingleError::WorldMismatch);
+        }
+
+        // SAFETY: The state is read-only and `world` is borrowed immutably
+        let single = unsafe {
+            query_single(self.0.as_readonly(), world.as_unsafe_world_cell_readonly(), world.last_change_tick(), world.read_change_tick())
+        };
+
+        single.map_err(SingleError::Query)
+    }
+}
+
+
+/// Returns how many entities match the filter `F`, without resolving any data
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::count_matches;
+/// # #[derive(Component)]
+/// # struct Player;
+/// let mut world = World::new();
+/// assert_eq!(count_matches::<With<Player>>(&mut world), 0);
+/// 
+/// world.spawn(Player);
+/// assert_eq!(count_matches::<With<Player>>(&mut world), 1);
+/// 
+/// world.spawn(Player);
+/// world.spawn(Player);
+/// assert_eq!(count_matches::<With<Player>>(&mut world), 3);
+/// ```
+pub fn count_matches<F: QueryFilter + 'static>(world: &mut World) -> usize {
+    world.query_filtered::<(), F>().iter(world).count()
+}
+
+
+/// Single entity lookups on [`World`], for exclusive systems and tests
+/// 
+/// ## Example
+/// 
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_single::prelude::*;
+/// # #[derive(Component)]
+/// # struct Player;
+/// pub fn reset_player(world: &mut World) {
+///     world.single::<&mut Transform, With<Player>>().translation = Vec3::ZERO;
+/// }
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(1.0, 2.0, 3.0), Player));
+/// # reset_player(&mut world);
+/// # assert!(world.get_single::<&Transform, ()>().is_ok_and(|transform| transform.translation == Vec3::ZERO));
+/// # world.spawn(Transform::default());
+/// # assert!(world.get_single::<&Transform, ()>().is_err());
+/// ```
+pub trait WorldSingleExt {
+    /// Resolves the single item of the query, panicking if there is not exactly one match
+    fn single<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> D::Item<'_>;
+
+    /// Resolves the single item of the query, returning an error if there is not exactly one match
+    fn get_single<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> Result<D::Item<'_>, SingleError>;
+
+    /// Same as [`WorldSingleExt::single`], named for the guarantee: panics on no match as well as on several
+    /// 
+    /// ```rust,should_panic
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// let mut world = World::new();
+    /// world.spawn((Transform::default(), Player));
+    /// world.expect_exactly_one::<&mut Transform, With<Player>>().translation.y = 1.0;
+    /// 
+    /// world.spawn((Transform::default(), Player));
+    /// world.expect_exactly_one::<&Transform, With<Player>>();
+    /// ```
+    fn expect_exactly_one<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> D::Item<'_> {
+        self.single::<D, F>()
+    }
+
+    /// Same as [`WorldSingleExt::get_single`], named for the guarantee: fails on no match as well as on several
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// let mut world = World::new();
+    /// assert!(world.get_exactly_one::<&Transform, With<Player>>().is_err());
+    /// 
+    /// world.spawn((Transform::default(), Player));
+    /// assert!(world.get_exactly_one::<&Transform, With<Player>>().is_ok());
+    /// 
+    /// world.spawn((Transform::default(), Player));
+    /// assert!(world.get_exactly_one::<&Transform, With<Player>>().is_err());
+    /// ```
+    fn get_exactly_one<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> Result<D::Item<'_>, SingleError> {
+        self.get_single::<D, F>()
+    }
+
+    /// Same as [`WorldSingleExt::get_single`], named after [`World::query_filtered`] which it resolves
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// let mut world = World::new();
+    /// world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Player));
+    /// world.spawn(Transform::default());
+    /// 
+    /// let player = world.query_filtered_single::<&Transform, With<Player>>().unwrap();
+    /// assert_eq!(player.translation.y, 2.0);
+    /// assert!(world.query_filtered_single::<&Transform, ()>().is_err());
+    /// ```
+    fn query_filtered_single<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> Result<D::Item<'_>, SingleError> {
+        self.get_single::<D, F>()
+    }
+
+    /// Same as [`WorldSingleExt::single`], but keeps the [`QueryState`] in a [`CachedSingleState`] resource
+    /// 
+    /// Later calls only update the cached state with new archetypes instead of building it again.
+    /// Change detection is relative to the last change tick of the world, like [`World::query`].
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Stunned;
+    /// 
+    /// let mut world = World::new();
+    /// let player = world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player)).id();
+    /// assert_eq!(world.single_cached::<&Transform, With<Player>>().translation.y, 1.0);
+    /// 
+    /// // Moves the player to a new archetype, which the cached state picks up
+    /// world.entity_mut(player).insert(Stunned);
+    /// world.single_cached::<&mut Transform, With<Player>>().translation.y = 2.0;
+    /// assert_eq!(world.single_cached::<&Transform, With<Player>>().translation.y, 2.0);
+    /// ```
+    fn single_cached<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> D::Item<'_>;
+
+    /// Resolves the single read-only item through the [`CachedSingleState`] of [`WorldSingleExt::single_cached`]
+    /// 
+    /// Only needs a shared borrow, so the cached state is not refreshed and entities in archetypes created
+    /// since its last use are not seen. Fails with [`SingleError::Uninitialized`] if there is no cached state
+    /// for the query yet.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// let mut world = World::new();
+    /// world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Player));
+    /// assert!(world.get_single_cached::<&Transform, With<Player>>().is_err());
+    /// 
+    /// world.single_cached::<&Transform, With<Player>>();
+    /// let world = &world;
+    /// assert_eq!(world.get_single_cached::<&Transform, With<Player>>().unwrap().translation.y, 1.0);
+    /// ```
+    fn get_single_cached<D: QueryData + 'static, F: QueryFilter + 'static>(&self) -> Result<ROQueryItem<'_, D>, SingleError>;
+
+    /// Resolves the single item of the query and runs `f` with it, returning the result of `f`
+    /// 
+    /// The item can not escape the closure, which keeps the borrow of the world scoped to it.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    /// 
+    /// let mut world = World::new();
+    /// world.spawn((Health(40), Player));
+    /// 
+    /// let healed = world.with_single::<&mut Health, With<Player>, _>(|mut health| {
+    ///     health.0 += 25;
+    ///     health.0 >= 50
+    /// });
+    /// assert!(healed.unwrap());
+    /// assert_eq!(world.single::<&Health, ()>().0, 65);
+    /// 
+    /// world.spawn((Health(10), Player));
+    /// assert!(world.with_single::<&Health, With<Player>, _>(|health| health.0).is_err());
+    /// ```
+    fn with_single<D: QueryData + 'static, F: QueryFilter + 'static, R>(&mut self, f: impl FnOnce(D::Item<'_>) -> R) -> Result<R, SingleError>;
+
+    /// Returns the single entity matching the filter `F`, building it with `init` if nothing matches
+    /// 
+    /// Like a lazily initialized resource, `init` only runs until the entity it returns matches `F`.
+    /// Panics if several entities match, or if the entity returned by `init` does not match.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct MainCamera;
+    /// 
+    /// let mut world = World::new();
+    /// let mut builds = 0;
+    /// let mut build_camera = |world: &mut World| {
+    ///     builds += 1;
+    ///     world.spawn((Transform::default(), MainCamera)).id()
+    /// };
+    /// 
+    /// let first = world.single_or_init::<With<MainCamera>>(&mut build_camera);
+    /// let second = world.single_or_init::<With<MainCamera>>(&mut build_camera);
+    /// assert_eq!(first, second);
+    /// assert_eq!(builds, 1);
+    /// ```
+    fn single_or_init<F: QueryFilter + 'static>(&mut self, init: impl FnOnce(&mut World) -> Entity) -> Entity;
+
+    /// Returns the entity matching `F` whose `K` equals `key`, spawning `(key, bundle)` if there is none
+    /// 
+    /// The bundle has to make the spawned entity match `F`, so that the next call finds it again.
+    /// Panics if it does not, or if several entities matching `F` carry the same key.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component, PartialEq)]
+    /// struct LevelId(u32);
+    /// 
+    /// #[derive(Component)]
+    /// struct Level;
+    /// 
+    /// let mut world = World::new();
+    /// let first = world.entity_or_insert::<_, With<Level>>(LevelId(1), Level);
+    /// let second = world.entity_or_insert::<_, With<Level>>(LevelId(2), Level);
+    /// assert_ne!(first, second);
+    /// assert_eq!(world.entity_or_insert::<_, With<Level>>(LevelId(1), Level), first);
+    /// assert_eq!(world.entity_or_insert::<_, With<Level>>(LevelId(2), Level), second);
+    /// assert_eq!(world.query::<&LevelId>().iter(&world).count(), 2);
+    /// ```
+    /// 
+    /// A bundle that does not make the entity match the filter panics:
+    /// ```rust,should_panic
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component, PartialEq)]
+    /// # struct LevelId(u32);
+    /// # #[derive(Component)]
+    /// # struct Level;
+    /// let mut world = World::new();
+    /// world.entity_or_insert::<_, With<Level>>(LevelId(1), ());
+    /// ```
+    fn entity_or_insert<K: Component + PartialEq, F: QueryFilter + 'static>(&mut self, key: K, bundle: impl Bundle) -> Entity;
+
+    /// Resolves several singles at once, one per `(D, F)` pair of the [`SingleTuple`] `T`
+    /// 
+    /// Returns a tuple with one read-only result per pair, so the pairs may overlap freely.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(Component)]
+    /// # struct Enemy;
+    /// let mut world = World::new();
+    /// world.spawn((Transform::from_xyz(0.0, 1.0, 0.0), Name::new("Hero"), Player));
+    /// world.spawn((Transform::from_xyz(0.0, 2.0, 0.0), Camera::default()));
+    /// 
+    /// let (player, camera) = world.get_singles::<((&Transform, With<Player>), (&Transform, With<Camera>))>();
+    /// assert_eq!(player.unwrap().translation.y, 1.0);
+    /// assert_eq!(camera.unwrap().translation.y, 2.0);
+    /// 
+    /// let (name, player, enemy) = world.get_singles::<((&Name, ()), (Entity, With<Player>), (Entity, With<Enemy>))>();
+    /// assert_eq!(name.unwrap().as_str(), "Hero");
+    /// assert!(player.is_ok());
+    /// assert!(enemy.is_err());
+    /// ```
+    fn get_singles<T: SingleTuple>(&mut self) -> T::Output<'_>;
+
+    /// Despawns the single entity matching the filter `F` together with all of its descendants
+    /// 
+    /// Panics if there is not exactly one match. Available with the `hierarchy` feature.
+    /// 
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_single::prelude::*;
+    /// #[derive(Component)]
+    /// struct MenuRoot;
+    /// 
+    /// let mut world = World::new();
+    /// let root = world.spawn(MenuRoot).id();
+    /// let button = world.spawn_empty().set_parent(root).id();
+    /// let label = world.spawn_empty().set_parent(button).id();
+    /// let unrelated = world.spawn_empty().id();
+    /// 
+    /// world.despawn_single_recursive::<With<MenuRoot>>();
+    /// assert!([root, button, label].iter().all(|entity| world.get_entity(*entity).is_none()));
+    /// assert!(world.get_entity(unrelated).is_some());
+    /// ```
+    #[cfg(feature = "hierarchy")]
+    fn despawn_single_recursive<F: QueryFilter + 'static>(&mut self);
+}
+
+impl WorldSingleExt for World {
+    fn single<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> D::Item<'_> {
+        self.get_single::<D, F>().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_single<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> Result<D::Item<'_>, SingleError> {
+        let state = self.query_filtered::<D, F>();
+
+        // SAFETY: `self` is borrowed mutably for the lifetime of the item, so nothing else accesses it.
+        // The state was created from this world and its archetypes are up to date.
+        let single = unsafe { query_single(&state, self.as_unsafe_world_cell(), self.last_change_tick(), self.change_tick()) };
+
+        single.map_err(SingleError::Query)
+    }
+
+    fn single_cached<D: QueryData + 'static, F: QueryFilter + 'static>(&mut self) -> D::Item<'_> {
+        if !self.contains_resource::<CachedSingleState<D, F>>() {
+            let state = CachedSingleState::<D, F>::new(self);
+            self.insert_resource(state);
+        }
+
+        let world = self.as_unsafe_world_cell();
+
+        // SAFETY: `self` is borrowed mutably, so nothing else accesses the world. The resource and the
+        // components read by the query are disjoint, and the state was created from this world.
+        let single = unsafe {
+            let mut cached = world.get_resource_mut::<CachedSingleState<D, F>>().unwrap();
+            let state = &mut cached.bypass_change_detection().0;
+            state.update_archetypes_unsafe_world_cell(world);
+
+            query_single(state, world, world.last_change_tick(), world.change_tick())
+        };
+
+        single.unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_single_cached<D: QueryData + 'static, F: QueryFilter + 'static>(&self) -> Result<ROQueryItem<'_, D>, SingleError> {
+        self.get_resource::<CachedSingleState<D, F>>().ok_or(SingleError::Uninitialized)?.get(self)
+    }
+
+    fn with_single<D: QueryData + 'static, F: QueryFilter + 'static, R>(&mut self, f: impl FnOnce(D::Item<'_>) -> R) -> Result<R, SingleError> {
+        Ok(f(self.get_single::<D, F>()?))
+    }
+
+    fn single_or_init<F: QueryFilter + 'static>(&mut self, init: impl FnOnce(&mut World) -> Entity) -> Entity {
+        match self.get_single::<Entity, F>() {
+            Ok(entity) => entity,
+            Err(SingleError::Query(QuerySingleError::NoEntities(_))) => {
+                let entity = init(self);
+
+                assert!(
+                    self.query_filtered::<(), F>().get(self, entity).is_ok(),
+                    "Entity {entity:?} built for {} does not match it",
+                    core::any::type_name::<F>(),
+                );
+
+                entity
+            }
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    fn entity_or_insert<K: Component + PartialEq, F: QueryFilter + 'static>(&mut self, key: K, bundle: impl Bundle) -> Entity {
+        let mut state = self.query_filtered::<(Entity, &K), F>();
+        let mut matches = state
+            .iter(self)
+            .filter(|(_, candidate)| **candidate == key)
+            .map(|(entity, _)| entity);
+        let found = (matches.next(), matches.next());
+
+        match found {
+            (Some(entity), None) => entity,
+            (None, _) => {
+                let entity = self.spawn((key, bundle)).id();
+
+                assert!(
+                    state.get(self, entity).is_ok(),
+                    "Entity {entity:?} spawned for key {} does not match {}",
+                    core::any::type_name::<K>(),
+                    core::any::type_name::<F>(),
+                );
+
+                entity
+            }
+            (Some(_), Some(_)) => panic!(
+                "Multiple entities with the same key {} fit the query {}",
+                core::any::type_name::<K>(),
+                core::any::type_name::<QueryState<(Entity, &K), F>>(),
+            ),
+        }
+    }
+
+    fn get_singles<T: SingleTuple>(&mut self) -> T::Output<'_> {
+        T::get_singles(self)
+    }
+
+    #[cfg(feature = "hierarchy")]
+    fn despawn_single_recursive<F: QueryFilter + 'static>(&mut self) {
+        use bevy_hierarchy::DespawnRecursiveExt;
+
+        let entity = self.single::<Entity, F>();
+        self.entity_mut(entity).despawn_recursive();
+    }
+}
+
+
+/// Tuple of `(D, F)` pairs resolved together by [`WorldSingleExt::get_singles`]
+/// 
+/// Implemented for tuples of 2 to 8 pairs.
+pub trait SingleTuple {
+    /// Tuple of one result per pair
+    type Output<'w>;
+
+    /// Resolves every pair against `world`
+    fn get_singles(world: &mut World) -> Self::Output<'_>;
+}
+
+macro_rules! impl_single_tuple {
+    ($(($data:ident, $filter:ident)),*) => {
+        impl<$($data: QueryData + 'static, $filter: QueryFilter + 'static,)*> SingleTuple for ($(($data, $filter),)*) {
+            type Output<'w> = ($(Result<ROQueryItem<'w, $data>, SingleError>,)*);
+
+            #[allow(non_snake_case)]
+            fn get_singles(world: &mut World) -> Self::Output<'_> {
+                let ($($data,)*) = ($(world.query_filtered::<$data, $filter>(),)*);
+                let world: &World = world;
+
+                // SAFETY: The states are read-only and `world` is borrowed immutably
+                ($(unsafe {
+                    query_single($data.as_readonly(), world.as_unsafe_world_cell_readonly(), world.last_change_tick(), world.read_change_tick())
+                }.map_err(SingleError::Query),)*)
+            }
+        }
+    };
+}
+
+impl_single_tuple!((D0, F0), (D1, F1));
+impl_single_tuple!((D0, F0), (D1, F1), (D2, F2));
+impl_single_tuple!((D0, F0), (D1, F1), (D2, F2), (D3, F3));
+impl_single_tuple!((D0, F0), (D1, F1), (D2, F2), (D3, F3), (D4, F4));
+impl_single_tuple!((D0, F0), (D1, F1), (D2, F2), (D3, F3), (D4, F4), (D5, F5));
+impl_single_tuple!((D0, F0), (D1, F1), (D2, F2), (D3, F3), (D4, F4), (D5, F5), (D6, F6));
+impl_single_tuple!((D0, F0), (D1, F1), (D2, F2), (D3, F3), (D4, F4), (D5, F5), (D6, F6), (D7, F7));